@@ -0,0 +1,488 @@
+use std::{collections::BTreeSet, env, fs, path::Path, process};
+
+use shared::{
+    expected::Verdict,
+    fetch::InputProvider,
+    report::{self, Format, Record},
+    timing::{benchmark_table, timing_table, Timings},
+    AocResult, Params,
+};
+
+mod registry;
+mod scaffold;
+
+const USAGE: &str = "Usage: aoc <run|test|verify|params|fetch> <days>
+       aoc solve <day> <file> [<name>=<value>]...
+       aoc bench <days> [runs]
+       aoc report <days> <file>...
+       aoc new <day>
+
+  run:    solve each day's input
+  solve:  solve a single day against any file, or `-` to read the input from stdin, with any
+          puzzle parameters it needs (like day 15's `row=2000000`), which override those
+          recorded in data/day-{n}/expected.toml when the file is one of the day's own
+  test:   solve each day's test data
+  verify: solve test data and input, comparing against data/day-{n}/expected.toml
+  params: list the puzzle parameters each day reads, with their defaults
+  bench:  solve each day's input `runs` times (default 10), reporting min/median/max per phase
+  report: verify, then write each part's answer, expected answer, verdict and duration to
+          every given file, as JSON or CSV depending on its extension (`.json`, `.csv`)
+  new:    generate and register the crate and data files for a new day
+  fetch:  download each day's input into data/day-{n}/input.txt, unless it's already there;
+          needs AOC_SESSION set to your session cookie (AOC_BASE_URL overrides the server)
+
+  days: `all`, a single day (`17`), a range (`1-5`), or a comma separated
+        list of those (`1,3,10-12`)";
+
+const DEFAULT_RUNS: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Run,
+    Test,
+    Verify,
+    Bench(usize),
+    Report,
+    New,
+    Fetch,
+    Solve,
+    Params,
+}
+impl TryFrom<&str> for Mode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "run" => Ok(Mode::Run),
+            "test" => Ok(Mode::Test),
+            "verify" => Ok(Mode::Verify),
+            "bench" => Ok(Mode::Bench(DEFAULT_RUNS)),
+            "report" => Ok(Mode::Report),
+            "fetch" => Ok(Mode::Fetch),
+            "params" => Ok(Mode::Params),
+            _ => Err(format!("Unrecognized command: {}", value)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Command {
+    mode: Mode,
+    days: Vec<usize>,
+    /// Files to write a report to, with the format each should be written in
+    outputs: Vec<(String, Format)>,
+    /// A file (or `-` for stdin) to solve in place of the day's own inputs
+    input: Option<String>,
+    /// Puzzle parameters to solve that file with
+    params: Params,
+}
+impl Command {
+    fn parse(args: &[String], known_days: &[usize]) -> Result<Command, String> {
+        match args {
+            [mode, day] if mode == "new" => Ok(Command {
+                mode: Mode::New,
+                days: vec![parse_new_day(day, known_days)?],
+                outputs: vec![],
+                input: None,
+                params: Params::new(),
+            }),
+            [mode, day, input, params @ ..] if mode == "solve" => Ok(Command {
+                mode: Mode::Solve,
+                days: parse_days(day, known_days)?,
+                outputs: vec![],
+                input: Some(input.to_owned()),
+                params: Params::parse_assignments(params)?,
+            })
+            .and_then(|command| match command.days.len() {
+                1 => Ok(command),
+                _ => Err("Expected a single day to solve".to_owned()),
+            }),
+            [mode, selection, outputs @ ..] if mode == "report" => Ok(Command {
+                mode: Mode::Report,
+                days: parse_days(selection, known_days)?,
+                outputs: parse_outputs(outputs)?,
+                input: None,
+                params: Params::new(),
+            }),
+            [mode, selection] => Ok(Command {
+                mode: Mode::try_from(mode.as_str())?,
+                days: parse_days(selection, known_days)?,
+                outputs: vec![],
+                input: None,
+                params: Params::new(),
+            }),
+            [mode, selection, runs] if mode == "bench" => Ok(Command {
+                mode: Mode::Bench(parse_runs(runs)?),
+                days: parse_days(selection, known_days)?,
+                outputs: vec![],
+                input: None,
+                params: Params::new(),
+            }),
+            _ => Err("Expected a command and a day selection".to_owned()),
+        }
+    }
+}
+
+fn parse_day(value: &str) -> Result<usize, String> {
+    value
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| format!("Unable to parse day: {}", value))
+}
+
+/// A day that can be generated: a puzzle day with no solution yet
+fn parse_new_day(value: &str, known_days: &[usize]) -> Result<usize, String> {
+    let day = parse_day(value)?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("Advent of Code days run from 1 to 25: {}", day));
+    }
+    if known_days.contains(&day) {
+        return Err(format!("day-{} already exists", day));
+    }
+    Ok(day)
+}
+
+fn parse_runs(value: &str) -> Result<usize, String> {
+    match value.trim().parse::<usize>() {
+        Ok(runs) if runs > 0 => Ok(runs),
+        _ => Err(format!("Expected a positive number of runs: {}", value)),
+    }
+}
+
+fn parse_outputs(outputs: &[String]) -> Result<Vec<(String, Format)>, String> {
+    if outputs.is_empty() {
+        return Err("Expected at least one report file".to_owned());
+    }
+    outputs
+        .iter()
+        .map(|path| {
+            Format::from_path(path)
+                .map(|format| (path.to_owned(), format))
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+/// Expand a day selection into a sorted, de-duplicated list of days, rejecting days with no solution
+fn parse_days(selection: &str, known_days: &[usize]) -> Result<Vec<usize>, String> {
+    if selection == "all" {
+        return Ok(known_days.to_vec());
+    }
+    let mut days = BTreeSet::new();
+    for part in selection.split(',') {
+        match part.split_once('-') {
+            Some((lo, hi)) => {
+                let (lo, hi) = (parse_day(lo)?, parse_day(hi)?);
+                if lo > hi {
+                    return Err(format!("Empty day range: {}", part));
+                }
+                days.extend(lo..=hi);
+            }
+            None => {
+                days.insert(parse_day(part)?);
+            }
+        }
+    }
+    match days.iter().find(|d| !known_days.contains(d)) {
+        Some(missing) => Err(format!("No solution registered for day {}", missing)),
+        None => Ok(days.into_iter().collect()),
+    }
+}
+
+/// Prefix each row's input name with its day, so rows from every day can share a table
+fn label<T>(day: usize, rows: Vec<(String, T)>) -> impl Iterator<Item = (String, T)> {
+    rows.into_iter()
+        .map(move |(name, row)| (format!("day-{} {}", day, name), row))
+}
+
+/// Keep the timings of every input that was solved, returning how many weren't. Their errors
+/// have already been printed along with the answers.
+fn tally<I>(solved: I, timings: &mut Vec<(String, Timings)>) -> usize
+where
+    I: Iterator<Item = (String, AocResult<Timings>)>,
+{
+    let mut errors = 0;
+    for (name, solved) in solved {
+        match solved {
+            Ok(solved) => timings.push((name, solved)),
+            Err(_) => errors += 1,
+        }
+    }
+    errors
+}
+
+fn new_day(day: usize) {
+    match scaffold::new_day(Path::new("."), day) {
+        Ok(created) => {
+            for path in created {
+                println!("Wrote {}", path.display());
+            }
+            println!("Next: cargo run -p aoc -- fetch {}", day);
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn fetch_inputs(days: &[usize]) {
+    let provider = InputProvider::from_env().unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+    let mut failed = false;
+    for day in days {
+        if provider.is_cached(*day) {
+            println!(
+                "day-{}: cached at {}",
+                day,
+                provider.input_path(*day).display()
+            );
+            continue;
+        }
+        match provider.fetch(*day) {
+            Ok(path) => println!("day-{}: fetched to {}", day, path.display()),
+            Err(e) => {
+                eprintln!("day-{}: error: {}", day, e);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let solutions = registry::solutions();
+    let known_days = solutions.iter().map(|(day, _)| *day).collect::<Vec<_>>();
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = Command::parse(&args, &known_days).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    match command.mode {
+        Mode::New => return new_day(command.days[0]),
+        Mode::Fetch => return fetch_inputs(&command.days),
+        _ => {}
+    }
+
+    let mut verifications = vec![];
+    let mut errors = 0;
+    let mut timings = vec![];
+    let mut benchmarks = vec![];
+    for (day, solution) in solutions {
+        if !command.days.contains(&day) {
+            continue;
+        }
+        println!("=================================");
+        println!("day-{}", day);
+        match command.mode {
+            Mode::Run => errors += tally(label(day, solution.run()), &mut timings),
+            Mode::Test => errors += tally(label(day, solution.test()), &mut timings),
+            Mode::Solve => {
+                let input = command
+                    .input
+                    .as_deref()
+                    .expect("Should have an input to solve");
+                match solution.solve_file(input, &command.params) {
+                    Ok(solved) => {
+                        print!("{}", solved.answers);
+                        timings.push((format!("day-{} {}", day, input), solved.timings));
+                    }
+                    Err(e) => {
+                        eprintln!("error: {}", e);
+                        errors += 1;
+                    }
+                }
+            }
+            Mode::New | Mode::Fetch => unreachable!("Handled without solving anything"),
+            Mode::Params => {
+                let params = solution.declared_params();
+                if params.is_empty() {
+                    println!("(none)");
+                }
+                for param in params {
+                    println!("{}", param);
+                }
+            }
+            Mode::Bench(runs) => match solution.bench(runs) {
+                Ok(benchmark) => benchmarks.push((format!("day-{} input", day), benchmark)),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    errors += 1;
+                }
+            },
+            Mode::Verify | Mode::Report => match solution.verify() {
+                Ok(results) => {
+                    for verification in results {
+                        println!("{}", verification);
+                        verifications.push(Record { day, verification });
+                    }
+                }
+                Err(e) => {
+                    println!("ERROR   {}", e);
+                    errors += 1;
+                }
+            },
+        }
+    }
+
+    match command.mode {
+        Mode::Run | Mode::Test | Mode::Solve => {
+            println!("=================================");
+            println!("{}", timing_table(&timings));
+            if errors > 0 {
+                process::exit(1);
+            }
+        }
+        Mode::Bench(_) => {
+            println!("=================================");
+            println!("{}", benchmark_table(&benchmarks));
+            if errors > 0 {
+                process::exit(1);
+            }
+        }
+        Mode::Verify => verify_summary(&verifications, errors),
+        Mode::New | Mode::Fetch | Mode::Params => {}
+        Mode::Report => {
+            for (path, format) in command.outputs.iter() {
+                if let Err(e) = fs::write(path, report::render(*format, &verifications)) {
+                    eprintln!("error: {}: {}", path, e);
+                    process::exit(1);
+                }
+                println!("Wrote {}", path);
+            }
+            verify_summary(&verifications, errors);
+        }
+    }
+}
+
+/// Tally the verdicts, exiting unsuccessfully if any part failed
+fn verify_summary(verifications: &[Record], errors: usize) {
+    let count = |f: fn(&Verdict) -> bool| {
+        verifications
+            .iter()
+            .filter(|r| f(&r.verification.verdict))
+            .count()
+    };
+    let failed = errors + count(|v| matches!(v, Verdict::Fail(_) | Verdict::Error(_)));
+    println!("=================================");
+    println!(
+        "{} passed, {} failed, {} missing",
+        count(|v| *v == Verdict::Pass),
+        failed,
+        count(|v| *v == Verdict::Missing)
+    );
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KNOWN: [usize; 5] = [1, 2, 3, 4, 9];
+
+    #[test]
+    fn should_parse_single_day() {
+        assert_eq!(parse_days("9", &KNOWN), Ok(vec![9]));
+    }
+
+    #[test]
+    fn should_parse_all_days() {
+        assert_eq!(parse_days("all", &KNOWN), Ok(KNOWN.to_vec()));
+    }
+
+    #[test]
+    fn should_parse_ranges_and_lists() {
+        assert_eq!(parse_days("1-3", &KNOWN), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days("9,2-3,3", &KNOWN), Ok(vec![2, 3, 9]));
+    }
+
+    #[test]
+    fn should_reject_unknown_days() {
+        assert!(parse_days("5", &KNOWN).is_err());
+        assert!(parse_days("3-1", &KNOWN).is_err());
+        assert!(parse_days("x", &KNOWN).is_err());
+    }
+
+    #[test]
+    fn should_parse_command() {
+        let args = vec!["test".to_owned(), "1-2".to_owned()];
+        assert_eq!(
+            Command::parse(&args, &KNOWN),
+            Ok(Command {
+                mode: Mode::Test,
+                days: vec![1, 2],
+                outputs: vec![],
+                input: None,
+                params: Params::new()
+            })
+        );
+        assert!(Command::parse(&args[..1], &KNOWN).is_err());
+        assert_eq!(Mode::try_from("fetch"), Ok(Mode::Fetch));
+        assert_eq!(Mode::try_from("params"), Ok(Mode::Params));
+    }
+
+    #[test]
+    fn should_parse_bench_runs() {
+        let args = ["bench", "9", "25"].map(|a| a.to_owned());
+        assert_eq!(
+            Command::parse(&args[..2], &KNOWN).map(|c| c.mode),
+            Ok(Mode::Bench(DEFAULT_RUNS))
+        );
+        assert_eq!(
+            Command::parse(&args, &KNOWN).map(|c| c.mode),
+            Ok(Mode::Bench(25))
+        );
+        assert!(Command::parse(&["bench", "9", "0"].map(|a| a.to_owned()), &KNOWN).is_err());
+        assert!(Command::parse(&["test", "9", "5"].map(|a| a.to_owned()), &KNOWN).is_err());
+    }
+
+    #[test]
+    fn should_parse_solve_input() {
+        let parse = |days: &str| {
+            let args = ["solve", days, "-"].map(|a| a.to_owned());
+            Command::parse(&args, &KNOWN).map(|c| (c.days, c.input))
+        };
+        assert_eq!(parse("9"), Ok((vec![9], Some("-".to_owned()))));
+        assert!(parse("1-2").is_err());
+        let args = ["solve", "9", "custom.txt", "row=10"].map(|a| a.to_owned());
+        assert_eq!(
+            Command::parse(&args, &KNOWN).map(|c| c.params.get("row")),
+            Ok(Some(10))
+        );
+        let args = ["solve", "9", "custom.txt", "row"].map(|a| a.to_owned());
+        assert!(Command::parse(&args, &KNOWN).is_err());
+    }
+
+    #[test]
+    fn should_parse_new_day() {
+        let parse = |day: &str| Command::parse(&["new".to_owned(), day.to_owned()], &KNOWN);
+        assert_eq!(parse("5").map(|c| c.days), Ok(vec![5]));
+        assert!(parse("9").is_err());
+        assert!(parse("26").is_err());
+    }
+
+    #[test]
+    fn should_parse_report_outputs() {
+        let args = ["report", "all", "results.json", "results.csv"].map(|a| a.to_owned());
+        assert_eq!(
+            Command::parse(&args, &KNOWN).map(|c| c.outputs),
+            Ok(vec![
+                ("results.json".to_owned(), Format::Json),
+                ("results.csv".to_owned(), Format::Csv)
+            ])
+        );
+        assert!(Command::parse(&args[..2], &KNOWN).is_err());
+        assert!(Command::parse(
+            &["report", "1", "results.txt"].map(|a| a.to_owned()),
+            &KNOWN
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use day_1::Day1;
+use day_10::Day10;
+use day_11::Day11;
+use day_12::Day12;
+use day_13::Day13;
+use day_14::Day14;
+use day_15::Day15;
+use day_16::Day16;
+use day_17::Day17;
+use day_18::Day18;
+use day_19::Day19;
+use day_2::Day2;
+use day_20::Day20;
+use day_21::Day21;
+use day_22::Day22;
+use day_3::Day3;
+use day_4::Day4;
+use day_5::Day5;
+use day_6::Day6;
+use day_7::Day7;
+use day_8::Day8;
+use day_9::Day9;
+use shared::AoCSolution;
+
+/// Every day known to the runner, keyed by day number.
+pub fn solutions() -> Vec<(usize, Box<dyn AoCSolution>)> {
+    vec![
+        (1, Box::new(Day1 {})),
+        (2, Box::new(Day2 {})),
+        (3, Box::new(Day3 {})),
+        (4, Box::new(Day4 {})),
+        (5, Box::new(Day5 {})),
+        (6, Box::new(Day6 {})),
+        (7, Box::new(Day7 {})),
+        (8, Box::new(Day8 {})),
+        (9, Box::new(Day9 {})),
+        (10, Box::new(Day10 {})),
+        (11, Box::new(Day11 {})),
+        (12, Box::new(Day12 {})),
+        (13, Box::new(Day13 {})),
+        (14, Box::new(Day14 {})),
+        (15, Box::new(Day15 {})),
+        (16, Box::new(Day16 {})),
+        (17, Box::new(Day17 {})),
+        (18, Box::new(Day18 {})),
+        (19, Box::new(Day19 {})),
+        (20, Box::new(Day20 {})),
+        (21, Box::new(Day21 {})),
+        (22, Box::new(Day22 {})),
+    ]
+}
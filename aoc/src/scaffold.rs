@@ -0,0 +1,257 @@
+//! Generates a new day: its crate, data files, and registration with the runner.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "day-{n}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+shared = { path = "../../shared" }
+"#;
+
+const LIB_TEMPLATE: &str = r#"use shared::{Answer, AoCProblem, AocResult, Input, Solution};
+
+pub struct Day{n} {}
+
+impl AoCProblem for Day{n} {
+    fn name(&self) -> String {
+        "day-{n}".to_owned()
+    }
+}
+
+impl Solution for Day{n} {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        Ok(input.lines().map(|line| line.to_owned()).collect())
+    }
+
+    fn part_one(&self, _lines: &Self::Parsed) -> AocResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part_two(&self, _lines: &Self::Parsed) -> AocResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn part_one_should() {
+        let input = Input::from_text("example", "");
+        let parsed = Day{n} {}.parse(&input).expect("Should parse");
+        let res = Day{n} {}.part_one(&parsed).expect("Should solve");
+        assert_eq!(res, Answer::Unsolved);
+    }
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"use day_{n}::Day{n};
+use shared::AoCSolution;
+
+fn main() {
+    Day{n} {}.test_and_run();
+}
+"#;
+
+const EXPECTED_TEMPLATE: &str = r#"# Known-correct answers, one table per input file (see shared/src/expected.rs)
+[test]
+
+[input]
+"#;
+
+fn render(template: &str, day: usize) -> String {
+    template.replace("{n}", &day.to_string())
+}
+
+/// Insert `line` before the first keyed line whose key sorts after it, or after the last
+/// keyed line if there is none. Lines without a key are left where they are.
+fn insert_sorted<K, F>(lines: &mut Vec<String>, line: String, key: F) -> Result<(), String>
+where
+    K: Ord,
+    F: Fn(&str) -> Option<K>,
+{
+    let new_key = key(&line).expect("Should insert a keyed line");
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect::<Vec<_>>();
+    let position = match keyed.iter().find(|(_, k)| *k > new_key) {
+        Some((i, _)) => *i,
+        None => match keyed.last() {
+            Some((i, _)) => i + 1,
+            None => return Err(format!("Unable to find where to insert '{}'", line.trim())),
+        },
+    };
+    lines.insert(position, line);
+    Ok(())
+}
+
+/// The registry, with the day imported and added to `solutions()`
+fn register(registry: &str, day: usize) -> Result<String, String> {
+    let mut lines = registry.lines().map(|l| l.to_owned()).collect::<Vec<_>>();
+    // Imports are in rustfmt's order, which compares module names rather than whole lines
+    insert_sorted(&mut lines, format!("use day_{}::Day{};", day, day), |l| {
+        l.strip_prefix("use day_")
+            .and_then(|rest| rest.split_once("::"))
+            .map(|(module, _)| module.to_owned())
+    })?;
+    insert_sorted(
+        &mut lines,
+        format!("        ({}, Box::new(Day{} {{}})),", day, day),
+        |l| {
+            l.trim()
+                .strip_prefix('(')
+                .and_then(|rest| rest.split_once(", Box::new("))
+                .and_then(|(n, _)| n.parse::<usize>().ok())
+        },
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The runner's manifest, with the day added as a dependency
+fn add_dependency(manifest: &str, day: usize) -> Result<String, String> {
+    let mut lines = manifest.lines().map(|l| l.to_owned()).collect::<Vec<_>>();
+    insert_sorted(
+        &mut lines,
+        format!("day-{} = {{ path = \"../problems/day-{}\" }}", day, day),
+        |l| {
+            l.strip_prefix("day-")
+                .and_then(|rest| rest.split_once(' '))
+                .and_then(|(n, _)| n.parse::<usize>().ok())
+        },
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn write(path: PathBuf, contents: &str, created: &mut Vec<PathBuf>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+    }
+    fs::write(&path, contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+    created.push(path);
+    Ok(())
+}
+
+/// Generate `day` under the workspace at `root`, returning every file written.
+/// Refuses to touch a day that already has a crate or is already registered; data files that
+/// already exist (e.g. a downloaded input) are kept as they are.
+pub fn new_day(root: &Path, day: usize) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = root.join(format!("problems/day-{}", day));
+    if crate_dir.exists() {
+        return Err(format!(
+            "day-{} already exists at {}",
+            day,
+            crate_dir.display()
+        ));
+    }
+    let registry_path = root.join("aoc/src/registry.rs");
+    let registry = read(&registry_path)?;
+    if registry.contains(&format!("use day_{}::", day)) {
+        return Err(format!("day-{} is already registered", day));
+    }
+    let manifest_path = root.join("aoc/Cargo.toml");
+    // Work out every edit before writing anything, so a failure leaves the workspace untouched
+    let registry = register(&registry, day)?;
+    let manifest = add_dependency(&read(&manifest_path)?, day)?;
+
+    let mut created = vec![];
+    write(
+        crate_dir.join("Cargo.toml"),
+        &render(CARGO_TEMPLATE, day),
+        &mut created,
+    )?;
+    write(
+        crate_dir.join("src/lib.rs"),
+        &render(LIB_TEMPLATE, day),
+        &mut created,
+    )?;
+    write(
+        crate_dir.join("src/main.rs"),
+        &render(MAIN_TEMPLATE, day),
+        &mut created,
+    )?;
+    let data_dir = root.join(format!("data/day-{}", day));
+    for (name, contents) in [
+        ("test.txt", ""),
+        ("input.txt", ""),
+        ("expected.toml", EXPECTED_TEMPLATE),
+    ] {
+        if !data_dir.join(name).exists() {
+            write(data_dir.join(name), contents, &mut created)?;
+        }
+    }
+    write(registry_path, &registry, &mut created)?;
+    write(manifest_path, &manifest, &mut created)?;
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const REGISTRY: &str = "use day_1::Day1;
+use day_10::Day10;
+use day_20::Day20;
+use day_9::Day9;
+use shared::AoCSolution;
+
+pub fn solutions() -> Vec<(usize, Box<dyn AoCSolution>)> {
+    vec![
+        (1, Box::new(Day1 {})),
+        (9, Box::new(Day9 {})),
+        (10, Box::new(Day10 {})),
+        (20, Box::new(Day20 {})),
+    ]
+}
+";
+
+    #[test]
+    fn should_register_in_order() {
+        let registry = register(REGISTRY, 2).expect("Should register");
+        assert!(registry.contains("use day_10::Day10;\nuse day_2::Day2;\nuse day_20::Day20;\n"));
+        assert!(registry.contains("(1, Box::new(Day1 {})),\n        (2, Box::new(Day2 {})),\n"));
+        let registry = register(REGISTRY, 23).expect("Should register");
+        assert!(registry.contains("use day_20::Day20;\nuse day_23::Day23;\nuse day_9::Day9;\n"));
+        assert!(registry
+            .contains("(20, Box::new(Day20 {})),\n        (23, Box::new(Day23 {})),\n    ]"));
+    }
+
+    #[test]
+    fn should_refuse_existing_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).expect("Should create workspace");
+        fs::write(root.join("aoc/src/registry.rs"), REGISTRY).expect("Should write registry");
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nday-1 = { path = \"../problems/day-1\" }\n",
+        )
+        .expect("Should write manifest");
+
+        let created = new_day(&root, 5).expect("Should create day");
+        assert!(created.contains(&root.join("problems/day-5/src/lib.rs")));
+        assert!(read(&root.join("aoc/Cargo.toml"))
+            .expect("Should read manifest")
+            .ends_with("day-5 = { path = \"../problems/day-5\" }\n"));
+        assert!(new_day(&root, 5).is_err());
+        assert!(new_day(&root, 9).is_err());
+
+        fs::remove_dir_all(&root).expect("Should clean up");
+    }
+}
@@ -0,0 +1,48 @@
+use itertools::Itertools;
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+pub struct Day1 {}
+impl AoCProblem for Day1 {
+    fn name(&self) -> String {
+        "day-1".to_owned()
+    }
+}
+impl Solution for Day1 {
+    // Total calories carried by each elf, smallest first
+    type Parsed = Vec<i32>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        // Blank lines separate each elf's calories
+        let processed: Vec<i32> = input
+            .blocks()
+            .map(|block| {
+                block
+                    .lines()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        line.parse::<i32>()
+                            .map_err(|e| AocError::parse(input.path(), block.line + i, e))
+                    })
+                    .sum::<AocResult<i32>>()
+            })
+            .collect::<AocResult<Vec<_>>>()?
+            .into_iter()
+            .sorted()
+            .collect();
+        if processed.is_empty() {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected at least one elf",
+            ));
+        }
+        Ok(processed)
+    }
+
+    fn part_one(&self, processed: &Self::Parsed) -> AocResult<Answer> {
+        Ok(processed[processed.len() - 1].into())
+    }
+
+    fn part_two(&self, processed: &Self::Parsed) -> AocResult<Answer> {
+        Ok(processed.iter().rev().take(3).sum::<i32>().into())
+    }
+}
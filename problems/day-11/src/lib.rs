@@ -0,0 +1,197 @@
+use shared::{all_lcm, Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution};
+
+// Rounds to watch while worry is relieved after each inspection, and while it isn't
+const PART1_ROUNDS: Param<u64> = Param::new("part1_rounds", 20);
+const PART2_ROUNDS: Param<u64> = Param::new("part2_rounds", 10000);
+
+trait MonkeyProcessor {
+    fn process_rounds<T: Fn(u64) -> u64>(&mut self, n: u64, normalize: T);
+    fn answer(&self) -> u64;
+    fn apply_state_updates(&mut self, items_to_send: Vec<(u64, u64)>);
+}
+
+impl MonkeyProcessor for Vec<MonkeyState> {
+    fn process_rounds<T: Fn(u64) -> u64>(&mut self, n: u64, normalize: T) {
+        for _ in 0..n {
+            for i in 0..self.len() {
+                let send_items_to = self[i].process_items(&normalize);
+                self.apply_state_updates(send_items_to);
+            }
+        }
+    }
+
+    fn answer(&self) -> u64 {
+        let mut part_two_results = self.iter().map(|s| s.inspections).collect::<Vec<u64>>();
+        part_two_results.sort();
+        part_two_results.reverse();
+        part_two_results.iter().take(2).product()
+    }
+
+    fn apply_state_updates(&mut self, items_to_send: Vec<(u64, u64)>) {
+        for (item, address_u64) in items_to_send {
+            let address = address_u64 as usize;
+            self[address].receive(item);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Mult(u64),
+    Sum(u64),
+    Square,
+}
+impl Operation {
+    fn apply(&self, lhs: u64) -> u64 {
+        match self {
+            Operation::Mult(x) => lhs * x,
+            Operation::Sum(x) => lhs + x,
+            Operation::Square => lhs * lhs,
+        }
+    }
+}
+impl TryFrom<&str> for Operation {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let operand = |s: &str| {
+            s.parse::<u64>()
+                .map_err(|e| format!("invalid operand '{}': {}", s, e))
+        };
+        if value.eq("old * old") {
+            Ok(Operation::Square)
+        } else if let Some(rhs) = value.strip_prefix("old * ") {
+            Ok(Operation::Mult(operand(rhs)?))
+        } else if let Some(rhs) = value.strip_prefix("old + ") {
+            Ok(Operation::Sum(operand(rhs)?))
+        } else {
+            Err(format!("unrecognized operation '{}'", value))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MonkeyState {
+    items: Vec<u64>,
+    test: u64,
+    pass: u64,
+    fail: u64,
+    operation: Operation,
+    inspections: u64,
+}
+impl MonkeyState {
+    fn receive(&mut self, item: u64) {
+        self.items.push(item);
+    }
+
+    fn process_items<T>(&mut self, normalize: T) -> Vec<(u64, u64)>
+    where
+        T: Fn(u64) -> u64,
+    {
+        let mut send_items_to = vec![];
+        for item in self.items.clone() {
+            let new_item = normalize(self.operation.apply(item));
+            let send_to = if new_item.is_multiple_of(self.test) {
+                self.pass
+            } else {
+                self.fail
+            };
+            send_items_to.push((new_item, send_to));
+        }
+        self.inspections += send_items_to.len() as u64;
+        self.items.clear();
+        send_items_to
+    }
+}
+// Errors carry the offset of the offending line within the monkey's block
+impl TryFrom<&[&str]> for MonkeyState {
+    type Error = (usize, String);
+
+    fn try_from(lines: &[&str]) -> Result<Self, Self::Error> {
+        let field = |idx: usize, prefix: &str| -> Result<&str, (usize, String)> {
+            lines
+                .get(idx)
+                .and_then(|line| line.trim().strip_prefix(prefix))
+                .ok_or((idx, format!("expected '{}'", prefix)))
+        };
+        let number = |idx: usize, prefix: &str| -> Result<u64, (usize, String)> {
+            field(idx, prefix)?
+                .parse::<u64>()
+                .map_err(|e| (idx, e.to_string()))
+        };
+        let items = field(1, "Starting items: ")?
+            .split(", ")
+            .map(|e| e.parse::<u64>().map_err(|err| (1, err.to_string())))
+            .collect::<Result<Vec<u64>, _>>()?;
+        let operation = Operation::try_from(field(2, "Operation: new = ")?).map_err(|e| (2, e))?;
+        let test = number(3, "Test: divisible by ")?;
+        let pass = number(4, "If true: throw to monkey ")?;
+        let fail = number(5, "If false: throw to monkey ")?;
+        Ok(MonkeyState {
+            items,
+            test,
+            pass,
+            fail,
+            operation,
+            inspections: 0,
+        })
+    }
+}
+
+pub struct Monkeys {
+    initial_states: Vec<MonkeyState>,
+    part1_rounds: u64,
+    part2_rounds: u64,
+}
+
+pub struct Day11 {}
+impl AoCProblem for Day11 {
+    fn name(&self) -> String {
+        "day-11".to_owned()
+    }
+}
+impl Solution for Day11 {
+    type Parsed = Monkeys;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let initial_states: Vec<MonkeyState> = input
+            .blocks()
+            .map(|block| {
+                MonkeyState::try_from(block.lines().collect::<Vec<_>>().as_slice())
+                    .map_err(|(offset, e)| AocError::parse(input.path(), block.line + offset, e))
+            })
+            .collect::<AocResult<_>>()?;
+        if let Some(target) = initial_states
+            .iter()
+            .flat_map(|s| [s.pass, s.fail])
+            .find(|target| *target as usize >= initial_states.len())
+        {
+            return Err(AocError::invariant(format!(
+                "Items are thrown to monkey {}, which doesn't exist",
+                target
+            )));
+        }
+        Ok(Monkeys {
+            initial_states,
+            part1_rounds: PART1_ROUNDS.get(input)?,
+            part2_rounds: PART2_ROUNDS.get(input)?,
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![PART1_ROUNDS.info(), PART2_ROUNDS.info()]
+    }
+
+    fn part_one(&self, monkeys: &Self::Parsed) -> AocResult<Answer> {
+        let mut states = monkeys.initial_states.clone();
+        states.process_rounds(monkeys.part1_rounds, |x| x / 3);
+        Ok(states.answer().into())
+    }
+
+    fn part_two(&self, monkeys: &Self::Parsed) -> AocResult<Answer> {
+        let mut states_2 = monkeys.initial_states.clone();
+        let least_common_multiple = all_lcm(states_2.iter().map(|s| s.test).collect::<Vec<u64>>());
+        states_2.process_rounds(monkeys.part2_rounds, |x| x % least_common_multiple);
+        Ok(states_2.answer().into())
+    }
+}
@@ -0,0 +1,85 @@
+use shared::{
+    grid::{Grid, Pos},
+    search::{self, Graph},
+    Answer, AoCProblem, AocError, AocResult, Input, Solution,
+};
+
+fn can_reach(from: char, to: char) -> bool {
+    let normalized_from = if from == 'S' { 'a' } else { from };
+    let normalized_to = if to == 'E' { 'z' } else { to };
+    (normalized_to as i32) - (normalized_from as i32) <= 1
+}
+
+#[derive(Clone, Debug)]
+pub struct Heightmap {
+    nodes: Grid<char>,
+}
+impl Heightmap {
+    fn find_start(&self) -> Option<Pos> {
+        self.nodes.find(|v| *v == 'S')
+    }
+    fn find_end(&self) -> Option<Pos> {
+        self.nodes.find(|v| *v == 'E')
+    }
+    fn find_starts(&self) -> Vec<Pos> {
+        self.nodes
+            .iter()
+            .filter(|(_, v)| **v == 'S' || **v == 'a')
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    fn one_step_condition(&self, start: &Pos, end: &Pos) -> bool {
+        can_reach(self.nodes[*start], self.nodes[*end])
+    }
+}
+impl Graph for Heightmap {
+    type Node = Pos;
+
+    fn neighbors(&self, from: &Pos) -> Vec<(Pos, usize)> {
+        self.nodes
+            .neighbors4(*from)
+            .filter(|to| self.one_step_condition(from, to))
+            .map(|to| (to, 1))
+            .collect()
+    }
+}
+
+pub struct Day12 {}
+impl AoCProblem for Day12 {
+    fn name(&self) -> String {
+        "day-12".to_owned()
+    }
+}
+impl Solution for Day12 {
+    // The heightmap, with its start and end
+    type Parsed = (Heightmap, Pos, Pos);
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let grid = Heightmap {
+            nodes: Grid::parse(input, Ok::<char, String>)?,
+        };
+        let start = grid
+            .find_start()
+            .ok_or_else(|| AocError::parse_file(input.path(), "expected a start 'S'"))?;
+        let end = grid
+            .find_end()
+            .ok_or_else(|| AocError::parse_file(input.path(), "expected an end 'E'"))?;
+        Ok((grid, start, end))
+    }
+
+    fn part_one(&self, (grid, start, end): &Self::Parsed) -> AocResult<Answer> {
+        let part_one = search::bfs(grid, *start)
+            .get(end)
+            .ok_or_else(|| AocError::invariant("No path from the start reaches the end"))?;
+        Ok(part_one.into())
+    }
+
+    fn part_two(&self, (grid, _, end): &Self::Parsed) -> AocResult<Answer> {
+        let starts = grid.find_starts();
+        let part_two = search::multi_source_bfs(grid, starts)
+            .get(end)
+            .ok_or_else(|| AocError::invariant("No path from any start reaches the end"))?;
+        Ok(part_two.into())
+    }
+}
@@ -0,0 +1,159 @@
+use std::cmp::Ordering;
+
+use serde_json::Value;
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+// If both values are integers, the lower integer should come first. If the left integer is lower than the
+// right integer, the inputs are in the right order. If the left integer is higher than the right integer, the
+// inputs are not in the right order. Otherwise, the inputs are the same integer; continue checking the next
+// part of the input.
+
+// If both values are lists, compare the first value of each list, then the second value, and so on. If the
+// left list runs out of items first, the inputs are in the right order. If the right list runs out of items
+//first, the inputs are not in the right order. If the lists are the same length and no comparison makes a
+//decision about the order, continue checking the next part of the input.
+
+// If exactly one value is an integer, convert the integer to a list which contains that integer as its only
+// value, then retry the comparison. For example, if comparing [0,0,0] and 2, convert the right value to [2]
+// (a list containing 2); the result is then found by instead comparing [0,0,0] and [2].
+
+fn cmp(a: Value, b: Value) -> Option<bool> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) if a.as_i64() < b.as_i64() => Some(true),
+        (Value::Number(a), Value::Number(b)) if a.as_i64() > b.as_i64() => Some(false),
+        (Value::Number(_a), Value::Number(_b)) => None,
+        (Value::Array(ays), Value::Array(bs)) => {
+            let bs_len = bs.len();
+            let ays_len = ays.len();
+            for (a, b) in ays.iter().zip(bs) {
+                let res = cmp(a.to_owned(), b);
+                if let Some(result) = res {
+                    return Some(result);
+                }
+            }
+            match (ays_len, bs_len) {
+                (a, b) if b < a => Some(false),
+                (a, b) if a < b => Some(true),
+                _ => None,
+            }
+        }
+        (Value::Number(a), Value::Array(bs)) => {
+            cmp(Value::Array(vec![Value::Number(a)]), Value::Array(bs))
+        }
+        (Value::Array(ays), Value::Number(b)) => {
+            cmp(Value::Array(ays), Value::Array(vec![Value::Number(b)]))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+struct Packet {
+    left: Value,
+    right: Value,
+}
+impl Packet {
+    // None when the packets are identical, which leaves their order undecided
+    fn is_valid(&self) -> Option<bool> {
+        cmp(self.left.to_owned(), self.right.to_owned())
+    }
+}
+
+pub struct Day13 {}
+impl AoCProblem for Day13 {
+    fn name(&self) -> String {
+        "day-13".to_owned()
+    }
+}
+impl Solution for Day13 {
+    // Every packet, in the order they appear
+    type Parsed = Vec<Value>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let mut individual_packets = vec![];
+        for block in input.blocks() {
+            for (idx, line) in block.lines().enumerate() {
+                let packet = serde_json::from_str::<Value>(line.trim()).map_err(|e| {
+                    AocError::parse(
+                        input.path(),
+                        block.line + idx,
+                        format!("invalid packet: {}", e),
+                    )
+                    .at_column(e.column())
+                })?;
+                individual_packets.push(packet);
+            }
+        }
+        if individual_packets.len() % 2 != 0 {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected packets to come in pairs",
+            ));
+        }
+        Ok(individual_packets)
+    }
+
+    fn part_one(&self, individual_packets: &Self::Parsed) -> AocResult<Answer> {
+        let mut part_one = 0;
+        for (i, pair) in individual_packets.chunks(2).enumerate() {
+            let packet = Packet {
+                left: pair[0].clone(),
+                right: pair[1].clone(),
+            };
+            let is_valid = packet.is_valid().ok_or_else(|| {
+                AocError::invariant(format!("Pair {} is made of identical packets", i + 1))
+            })?;
+            if is_valid {
+                part_one += i + 1;
+            }
+        }
+        Ok(part_one.into())
+    }
+
+    fn part_two(&self, individual_packets: &Self::Parsed) -> AocResult<Answer> {
+        let mut individual_packets = individual_packets.clone();
+        individual_packets.push(serde_json::json!([[2]]));
+        individual_packets.push(serde_json::json!([[6]]));
+        individual_packets.sort_by(|a, b| {
+            cmp(a.to_owned(), b.to_owned())
+                .map(|r| if r { Ordering::Less } else { Ordering::Greater })
+                .unwrap_or(Ordering::Equal)
+        });
+        let dividers = individual_packets
+            .iter()
+            .enumerate()
+            .filter(|pair| {
+                let value = pair.1;
+                match value {
+                    Value::Array(v) => {
+                        if v.len() != 1 {
+                            return false;
+                        }
+                        let first = &v[0];
+                        match first {
+                            Value::Array(w) => {
+                                if w.len() != 1 {
+                                    return false;
+                                }
+                                let first = &w[0];
+                                if !first.is_number() {
+                                    return false;
+                                }
+                                let maybe_num = first.as_i64();
+                                if maybe_num.is_none() {
+                                    return false;
+                                }
+                                let num = maybe_num.unwrap();
+                                num == 2 || num == 6
+                            }
+                            _ => false,
+                        }
+                    }
+                    _ => false,
+                }
+            })
+            .map(|pair| 1 + pair.0)
+            .collect::<Vec<usize>>();
+        Ok((dividers[0] * dividers[1]).into())
+    }
+}
@@ -0,0 +1,208 @@
+use shared::{
+    sparse::{Point, SparseGrid},
+    Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution,
+};
+
+// Where the sand pours in from
+const SOURCE_X: Param<i64> = Param::new("source_x", 500);
+const SOURCE_Y: Param<i64> = Param::new("source_y", 0);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Rock,
+    Sand,
+}
+
+/// The cave as the puzzle draws it, from the source down to the lowest rock or sand
+pub fn draw(cave: &SparseGrid<Tile>, source: Point) -> String {
+    let Some(bounds) = cave.bounds() else {
+        return String::new();
+    };
+    cave.render_window(bounds.including(source), |point, tile| match tile {
+        Some(Tile::Rock) => '#',
+        Some(Tile::Sand) => 'o',
+        None if point == source => '+',
+        None => '.',
+    })
+}
+
+fn interpolate(start: Point, to: Point) -> Vec<Point> {
+    let diff = to - start;
+    let delta = Point::new(diff.x.signum(), diff.y.signum());
+    let mut result = vec![start];
+    let mut next = start;
+    while next != to {
+        next += delta;
+        result.push(next);
+    }
+    result
+}
+
+fn settle<F>(
+    lower_bound: i64,
+    occupied: &SparseGrid<Tile>,
+    sand: Point,
+    handle_bounds: F,
+) -> Option<Point>
+where
+    F: Fn(Point) -> Option<Point>,
+{
+    let mut next_node = sand;
+    loop {
+        let one_down = next_node + Point::new(0, 1);
+        let down_left = next_node + Point::new(-1, 1);
+        let down_right = next_node + Point::new(1, 1);
+
+        if one_down.y > lower_bound {
+            return handle_bounds(next_node);
+        } else if !occupied.contains(one_down) {
+            next_node = one_down;
+        } else if !occupied.contains(down_left) {
+            next_node = down_left;
+        } else if !occupied.contains(down_right) {
+            next_node = down_right;
+        } else {
+            return Some(next_node);
+        }
+    }
+}
+
+fn parse_path(line: &str) -> Result<Vec<Point>, String> {
+    line.split(" -> ")
+        .map(|p| {
+            let parts = p
+                .split_once(',')
+                .ok_or(format!("expected 'x,y', found '{}'", p))?;
+            let x = parts
+                .0
+                .parse::<i64>()
+                .map_err(|e| format!("invalid x coord '{}': {}", parts.0, e))?;
+            let y = parts
+                .1
+                .parse::<i64>()
+                .map_err(|e| format!("invalid y coord '{}': {}", parts.1, e))?;
+            Ok(Point::new(x, y))
+        })
+        .collect()
+}
+
+/// Pour sand until it either falls into the abyss or backs up to the source, returning the cave
+/// with every grain that came to rest
+pub fn pour<F, G>(
+    rocks: &SparseGrid<Tile>,
+    source: Point,
+    bounds_finder: F,
+    bounds_handler: G,
+) -> SparseGrid<Tile>
+where
+    F: Fn(i64) -> i64,
+    G: Fn(Point) -> Option<Point>,
+{
+    let mut occupied_points = rocks.clone();
+
+    // Parsing guarantees there's at least one rock
+    let lower_bound = bounds_finder(rocks.bounds().map(|b| b.max.y).unwrap_or(0));
+
+    loop {
+        let res = settle(lower_bound, &occupied_points, source, &bounds_handler);
+        // If settle returns None, then we've gone off the deep end
+        // (Part 1)
+        let Some(inner) = res else {
+            break;
+        };
+        occupied_points.insert(inner, Tile::Sand);
+        // If settle returns the source then that means we've backed up
+        // to the source point. (Part 2)
+        if source == inner {
+            break;
+        }
+    }
+    occupied_points
+}
+
+fn sand_count(cave: &SparseGrid<Tile>) -> usize {
+    cave.iter().filter(|(_, tile)| **tile == Tile::Sand).count()
+}
+
+pub struct Cave {
+    // Every point covered by rock
+    rocks: SparseGrid<Tile>,
+    source: Point,
+}
+
+pub struct Day14 {}
+impl AoCProblem for Day14 {
+    fn name(&self) -> String {
+        "day-14".to_owned()
+    }
+}
+impl Solution for Day14 {
+    type Parsed = Cave;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let rocks = input
+            .parse_lines(parse_path)?
+            .into_iter()
+            .flat_map(|path_segments| {
+                path_segments
+                    .windows(2)
+                    .flat_map(|segment_pair| interpolate(segment_pair[0], segment_pair[1]))
+                    .collect::<Vec<Point>>()
+            })
+            .map(|point| (point, Tile::Rock))
+            .collect::<SparseGrid<Tile>>();
+        if rocks.is_empty() {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected at least one rock path",
+            ));
+        }
+        Ok(Cave {
+            rocks,
+            source: Point::new(SOURCE_X.get(input)?, SOURCE_Y.get(input)?),
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![SOURCE_X.info(), SOURCE_Y.info()]
+    }
+
+    fn part_one(&self, cave: &Self::Parsed) -> AocResult<Answer> {
+        Ok(sand_count(&pour(&cave.rocks, cave.source, |i| i, |_point| None)).into())
+    }
+
+    fn part_two(&self, cave: &Self::Parsed) -> AocResult<Answer> {
+        Ok(sand_count(&pour(&cave.rocks, cave.source, |i| i + 1, Some)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn should_draw_sand_pile() {
+        let input = Input::from_text(
+            "example",
+            "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n",
+        );
+        let parsed = Day14 {}.parse(&input).expect("Should parse");
+        let cave = pour(&parsed.rocks, parsed.source, |i| i, |_point| None);
+        assert_eq!(
+            draw(&cave, parsed.source),
+            [
+                "......+...",
+                "..........",
+                "......o...",
+                ".....ooo..",
+                "....#ooo##",
+                "...o#ooo#.",
+                "..###ooo#.",
+                "....oooo#.",
+                ".o.ooooo#.",
+                "#########.",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::HashSet;
+
+use shared::{
+    geom::Point2,
+    interval::{Interval, IntervalSet},
+    Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution,
+};
+
+// The row to count covered positions on, and the largest coordinate the distress beacon can have
+const ROW: Param<i64> = Param::required("row");
+const MAX_BOUND: Param<i64> = Param::required("max_bound");
+
+// Returns Center, Distance, and Beacon
+fn line_to_points(line: &str) -> Result<(Point, usize, Point), String> {
+    let (x0, rest) = line
+        .strip_prefix("Sensor at x=")
+        .ok_or("expected 'Sensor at x='")?
+        .split_once(',')
+        .ok_or("expected ','")?;
+    let (y0, rest) = rest
+        .strip_prefix(" y=")
+        .ok_or("expected ' y='")?
+        .split_once(':')
+        .ok_or("expected ':'")?;
+    let (x1, rest) = rest
+        .strip_prefix(" closest beacon is at x=")
+        .ok_or("expected ' closest beacon is at x='")?
+        .split_once(',')
+        .ok_or("expected ','")?;
+    let y1 = rest.strip_prefix(" y=").ok_or("expected ' y='")?;
+
+    let coord = |value: &str| {
+        value
+            .parse::<i64>()
+            .map_err(|e| format!("invalid coordinate '{}': {}", value, e))
+    };
+    let start = Point {
+        x: coord(x0)?,
+        y: coord(y0)?,
+    };
+    let beacon = Point {
+        x: coord(x1)?,
+        y: coord(y1)?,
+    };
+    Ok((start, start.manhattan(&beacon) as usize, beacon))
+}
+
+type Point = Point2<i64>;
+
+// The positions on `on_line` within `distance` of `center`, minus the beacon itself
+fn coverage(
+    center: &Point,
+    distance: usize,
+    on_line: i64,
+    beacon: &Point,
+) -> Vec<(i64, Interval<i64>)> {
+    let y = on_line;
+    let remaining_distance = (distance as i64) - (on_line - center.y).abs();
+    if remaining_distance < 0 {
+        return vec![];
+    }
+    let v1 = center.x - remaining_distance;
+    let v2 = center.x + remaining_distance;
+    let candidate_interval = Interval::closed(v1.min(v2), v1.max(v2));
+    if beacon.y == on_line && candidate_interval.contains(beacon.x) {
+        vec![
+            (y, Interval::new(candidate_interval.start, beacon.x)),
+            (y, Interval::new(beacon.x + 1, candidate_interval.end)),
+        ]
+    } else {
+        vec![(y, candidate_interval)]
+    }
+}
+
+pub struct Report {
+    // Center, Distance, and Beacon of each sensor
+    sensors: Vec<(Point, usize, Point)>,
+    // The row to count covered positions on
+    on_line: i64,
+    // The distress beacon's coordinates are each at most this
+    max_bound: i64,
+}
+
+/// The distress beacon's position, boiled down to one number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TuningFrequency(pub i64);
+impl TuningFrequency {
+    fn of(position: Point) -> TuningFrequency {
+        TuningFrequency(position.x * 4000000 + position.y)
+    }
+}
+impl From<TuningFrequency> for Answer {
+    fn from(value: TuningFrequency) -> Self {
+        value.0.into()
+    }
+}
+
+// Whether some sensor's range reaches `point`
+fn covered(report: &Report, point: &Point) -> bool {
+    report
+        .sensors
+        .iter()
+        .any(|(center, distance, _)| center.manhattan(point) <= *distance as i64)
+}
+
+fn in_bounds(report: &Report, point: &Point) -> bool {
+    (0..=report.max_bound).contains(&point.x) && (0..=report.max_bound).contains(&point.y)
+}
+
+// The only position no sensor covers has to sit just outside the range of sensors on each side,
+// so it's where the edges of two sensors' ranges cross. Rotating the coordinates to u = x + y
+// and v = x - y turns those edges into lines u = c and v = c, so every crossing is just a pair
+// of values. The position could also be up against the edge of the search area, so the
+// crossings with that are tried too.
+fn find_by_boundaries(report: &Report) -> Option<Point> {
+    let max = report.max_bound;
+    let mut us = vec![];
+    let mut vs = vec![];
+    for (center, distance, _) in report.sensors.iter() {
+        let reach = *distance as i64 + 1;
+        let (u, v) = (center.x + center.y, center.x - center.y);
+        us.extend([u - reach, u + reach]);
+        vs.extend([v - reach, v + reach]);
+    }
+    let crossings = us
+        .iter()
+        .flat_map(|u| vs.iter().map(move |v| (*u, *v)))
+        .filter(|(u, v)| (u - v).rem_euclid(2) == 0)
+        .map(|(u, v)| Point::new((u + v) / 2, (u - v) / 2));
+    let against_edges = us
+        .iter()
+        .flat_map(|u| {
+            [
+                Point::new(0, *u),
+                Point::new(max, u - max),
+                Point::new(*u, 0),
+                Point::new(u - max, max),
+            ]
+        })
+        .chain(vs.iter().flat_map(|v| {
+            [
+                Point::new(0, -v),
+                Point::new(max, max - v),
+                Point::new(*v, 0),
+                Point::new(v + max, max),
+            ]
+        }))
+        .chain([
+            Point::new(0, 0),
+            Point::new(max, 0),
+            Point::new(0, max),
+            Point::new(max, max),
+        ]);
+    crossings
+        .chain(against_edges)
+        .find(|point| in_bounds(report, point) && !covered(report, point))
+}
+
+// The same search one row at a time: the position is the only value in bounds left uncovered on
+// its row, which may be at either edge. Far slower, but a useful check on the boundary search.
+fn find_by_rows(report: &Report) -> Option<Point> {
+    let beacons = report
+        .sensors
+        .iter()
+        .map(|(_, _, beacon)| *beacon)
+        .collect::<HashSet<Point>>();
+    for on_line in 0..=report.max_bound {
+        let mut interval_set = IntervalSet::new();
+        for (center, distance, beacon) in report.sensors.iter() {
+            let intervals = coverage(center, *distance, on_line, beacon);
+            for (_y, interval) in intervals {
+                interval_set.insert(interval);
+            }
+        }
+        for beacon in beacons.iter() {
+            if beacon.y == on_line {
+                interval_set.insert(Interval::closed(beacon.x, beacon.x))
+            }
+        }
+        let uncovered = interval_set.complement(Interval::closed(0, report.max_bound));
+        if uncovered.len() == 1 {
+            return uncovered
+                .iter()
+                .next()
+                .map(|only| Point::new(only.start, on_line));
+        }
+    }
+    None
+}
+
+pub struct Day15 {}
+impl Day15 {
+    /// Find the distress beacon both by crossing range boundaries and by scanning rows, and
+    /// check they agree
+    pub fn cross_check(&self, input: &Input) -> AocResult<String> {
+        let report = self.parse(input)?;
+        let by_boundaries = find_by_boundaries(&report);
+        let by_rows = find_by_rows(&report);
+        match (by_boundaries, by_rows) {
+            (Some(position), Some(scanned)) if position == scanned => Ok(format!(
+                "Both searches found the distress beacon at x={}, y={}, tuning frequency {}\n",
+                position.x,
+                position.y,
+                TuningFrequency::of(position).0
+            )),
+            _ => Err(AocError::invariant(format!(
+                "Boundary search found {:?} but the row scan found {:?}",
+                by_boundaries, by_rows
+            ))),
+        }
+    }
+}
+impl AoCProblem for Day15 {
+    fn name(&self) -> String {
+        "day-15".to_owned()
+    }
+}
+impl Solution for Day15 {
+    type Parsed = Report;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let sensors = input.parse_lines(line_to_points)?;
+        Ok(Report {
+            on_line: ROW.get(input)?,
+            max_bound: MAX_BOUND.get(input)?,
+            sensors,
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![ROW.info(), MAX_BOUND.info()]
+    }
+
+    fn part_one(&self, report: &Self::Parsed) -> AocResult<Answer> {
+        let on_line = report.on_line;
+        let mut interval_set = IntervalSet::new();
+        for (center, distance, beacon) in report.sensors.iter() {
+            let intervals = coverage(center, *distance, on_line, beacon);
+            for (_y, interval) in intervals {
+                interval_set.insert(interval);
+            }
+        }
+        Ok(interval_set.len().into())
+    }
+
+    fn part_two(&self, report: &Self::Parsed) -> AocResult<Answer> {
+        Ok(find_by_boundaries(report)
+            .map(TuningFrequency::of)
+            .map_or(Answer::Unsolved, Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::Params;
+
+    use super::*;
+
+    #[test]
+    fn should_compute_distance() {
+        let p = Point { x: 8, y: 7 };
+        let b = Point { x: 2, y: 10 };
+        let result = p.manhattan(&b);
+        assert_eq!(result, 9, "Distance should be 9");
+    }
+
+    #[test]
+    fn should_compute_line() {
+        let beacon = Point { x: 8, y: 20 };
+        let a = Point { x: 8, y: 7 };
+        let d = 1;
+        let line = a.y;
+        let intervals = coverage(&a, d, line, &beacon);
+        assert_eq!(intervals.len(), 1);
+        let (y, interval) = intervals[0];
+        assert_eq!(y, line);
+        assert_eq!(interval.len(), 3);
+        assert_eq!(interval.start, 7);
+        assert_eq!(interval.last(), Some(9));
+
+        let a = Point { x: 8, y: 7 };
+        let d = 2;
+        let line = a.y + 1;
+        let intervals = coverage(&a, d, line, &beacon);
+        assert_eq!(intervals.len(), 1);
+        let (y, interval) = intervals[0];
+        assert_eq!(y, line);
+        assert_eq!(interval.len(), 3);
+        assert_eq!(interval.start, 7);
+        assert_eq!(interval.last(), Some(9));
+
+        let a = Point { x: 8, y: 7 };
+        let d = 3;
+        let line = a.y + 1;
+        let intervals = coverage(&a, d, line, &beacon);
+        assert_eq!(intervals.len(), 1);
+        let (y, interval) = intervals[0];
+        assert_eq!(y, line);
+        assert_eq!(interval.len(), 5);
+        assert_eq!(interval.start, 6);
+        assert_eq!(interval.last(), Some(10));
+    }
+
+    #[test]
+    fn should_compute_split_line() {
+        let beacon = Point { x: 8, y: 8 };
+        let a = Point { x: 8, y: 7 };
+        let d = 3;
+        let line = a.y + 1;
+        let intervals = coverage(&a, d, line, &beacon);
+        assert_eq!(intervals.len(), 2);
+    }
+
+    #[test]
+    fn should_combine_intervals() {
+        let mut s = IntervalSet::new();
+        s.insert(Interval::closed(0, 10));
+        s.insert(Interval::closed(2, 12));
+        s.insert(Interval::closed(13, 14));
+
+        assert!(s.contains(0));
+        assert!(s.contains(14));
+        assert_eq!(s.iter().count(), 1);
+    }
+
+    #[test]
+    fn should_find_distress_beacon_both_ways() {
+        let input = Input::from_text(
+            "example",
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+",
+        )
+        .with_params(Params::from_iter([("row", 10), ("max_bound", 20)]));
+        let report = Day15 {}.parse(&input).expect("Should parse");
+        assert_eq!(find_by_boundaries(&report), Some(Point::new(14, 11)));
+        assert_eq!(find_by_rows(&report), Some(Point::new(14, 11)));
+        assert_eq!(
+            TuningFrequency::of(Point::new(14, 11)),
+            TuningFrequency(56000011)
+        );
+
+        // A beacon on the edge of the search area isn't between any two ranges
+        let input = Input::from_text(
+            "edge",
+            "Sensor at x=2, y=2: closest beacon is at x=2, y=0
+Sensor at x=0, y=2: closest beacon is at x=0, y=1
+Sensor at x=1, y=-1: closest beacon is at x=1, y=-2
+",
+        )
+        .with_params(Params::from_iter([("row", 0), ("max_bound", 2)]));
+        let report = Day15 {}.parse(&input).expect("Should parse");
+        assert_eq!(find_by_rows(&report), Some(Point::new(0, 0)));
+        assert_eq!(find_by_boundaries(&report), Some(Point::new(0, 0)));
+    }
+
+    #[test]
+    fn should_solve_in_memory_input() {
+        let input = Input::from_text(
+            "example",
+            "Sensor at x=8, y=7: closest beacon is at x=8, y=16\n",
+        );
+        // Which row to count is up to the input
+        assert!(Day15 {}.parse(&input).is_err());
+        let input = input.with_params(Params::from_iter([("row", 10), ("max_bound", 20)]));
+        let report = Day15 {}.parse(&input).expect("Should parse");
+        // Row 10 is within 9 of the sensor from x=2 to x=14
+        let result = Day15 {}.part_one(&report).expect("Should solve");
+        assert_eq!(result, Answer::Number(13));
+    }
+}
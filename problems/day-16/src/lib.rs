@@ -0,0 +1,267 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use shared::{
+    search::{self, Graph},
+    Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution,
+};
+
+const STARTING_POINT: &str = "AA";
+const MINUTES: Param<usize> = Param::new("minutes", 30);
+// Four minutes go on teaching the elephant
+const MINUTES_WITH_ELEPHANT: Param<usize> = Param::new("minutes_with_elephant", 26);
+
+pub struct AdjacencyList {
+    // The valves each valve has a tunnel to
+    edges: HashMap<String, Vec<String>>,
+    // Each valve's flow rate
+    rates: HashMap<String, usize>,
+}
+impl AdjacencyList {
+    fn merge(&mut self, other: &AdjacencyList) {
+        for (key, value) in other.edges.iter() {
+            if let Some(self_edges) = self.edges.get_mut(key) {
+                self_edges.extend(value.to_owned());
+            } else {
+                self.edges.insert(key.to_owned(), value.clone());
+            }
+        }
+        self.rates.extend(other.rates.clone());
+    }
+}
+impl Graph for AdjacencyList {
+    type Node = String;
+
+    // Following any tunnel takes a minute, whatever the valve's rate
+    fn neighbors(&self, valve: &String) -> Vec<(String, usize)> {
+        self.edges
+            .get(valve)
+            .map(|edges| edges.iter().map(|to| (to.clone(), 1)).collect())
+            .unwrap_or_default()
+    }
+}
+impl TryFrom<&str> for AdjacencyList {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parts = value
+            .split(' ')
+            .map(|p| p.to_owned())
+            .collect::<Vec<String>>();
+        if parts.len() < 10 {
+            return Err(
+                "expected 'Valve <name> has flow rate=<rate>; tunnels lead to valves <names>'"
+                    .to_owned(),
+            );
+        }
+        let from = parts[1].clone();
+        let rate = parts[4]
+            .strip_prefix("rate=")
+            .ok_or("expected 'rate='")?
+            .strip_suffix(';')
+            .ok_or("expected a trailing ';' after the rate")?
+            .parse::<usize>()
+            .map_err(|e| format!("invalid flow rate: {}", e))?;
+        let destinations = parts[9..]
+            .iter()
+            .map(|p| p.trim_end_matches(',').to_owned());
+        let mut edges = HashMap::new();
+        edges.insert(from.clone(), destinations.collect::<Vec<String>>());
+        let mut rates = HashMap::new();
+        rates.insert(from, rate);
+        Ok(AdjacencyList { edges, rates })
+    }
+}
+
+// Only the valves worth opening, plus the starting point, with how long it takes to walk
+// between each pair of them
+pub struct Network {
+    // The flow rate of each valve with one, indexed the same as `distances`
+    rates: Vec<usize>,
+    // Minutes to walk from one valve to another. The starting point is the last valve.
+    distances: Vec<Vec<usize>>,
+}
+impl Network {
+    fn start(&self) -> usize {
+        self.rates.len()
+    }
+
+    // Valves not yet in `opened` that can be reached and opened with time to spare, and the
+    // minutes left once each is flowing
+    fn next_valves(&self, at: usize, minutes: usize, opened: u64) -> Vec<(usize, usize)> {
+        (0..self.rates.len())
+            .filter(|next| opened & (1 << next) == 0)
+            .filter_map(|next| {
+                let remaining = minutes.checked_sub(self.distances[at][next] + 1)?;
+                (remaining > 0).then_some((next, remaining))
+            })
+            .collect()
+    }
+
+    // The most pressure that can still be released from `at`, with `minutes` left and the
+    // valves in `opened` already open
+    fn max_pressure(
+        &self,
+        at: usize,
+        minutes: usize,
+        opened: u64,
+        memo: &mut HashMap<(usize, usize, u64), usize>,
+    ) -> usize {
+        if let Some(best) = memo.get(&(at, minutes, opened)) {
+            return *best;
+        }
+        let best = self
+            .next_valves(at, minutes, opened)
+            .into_iter()
+            .map(|(next, remaining)| {
+                self.rates[next] * remaining
+                    + self.max_pressure(next, remaining, opened | 1 << next, memo)
+            })
+            .max()
+            .unwrap_or(0);
+        memo.insert((at, minutes, opened), best);
+        best
+    }
+
+    // Record the most pressure released by each set of valves that can be opened in time
+    fn explore(
+        &self,
+        at: usize,
+        minutes: usize,
+        opened: u64,
+        pressure: usize,
+        best: &mut HashMap<u64, usize>,
+    ) {
+        let best_for_opened = best.entry(opened).or_insert(0);
+        *best_for_opened = pressure.max(*best_for_opened);
+        for (next, remaining) in self.next_valves(at, minutes, opened) {
+            let released = self.rates[next] * remaining;
+            self.explore(
+                next,
+                remaining,
+                opened | 1 << next,
+                pressure + released,
+                best,
+            );
+        }
+    }
+}
+impl TryFrom<&AdjacencyList> for Network {
+    type Error = String;
+
+    fn try_from(adjacencies: &AdjacencyList) -> Result<Self, Self::Error> {
+        if !adjacencies.edges.contains_key(STARTING_POINT) {
+            return Err(format!("expected a valve {}", STARTING_POINT));
+        }
+        let mut valves = adjacencies
+            .rates
+            .iter()
+            .filter(|(_, rate)| **rate > 0)
+            .map(|(valve, _)| valve.to_owned())
+            .collect::<Vec<String>>();
+        valves.sort();
+        // Opened valves are tracked as bits of a u64
+        if valves.len() > 64 {
+            return Err(format!(
+                "expected at most 64 valves with a flow rate, found {}",
+                valves.len()
+            ));
+        }
+        let rates = valves.iter().map(|v| adjacencies.rates[v]).collect();
+        valves.push(STARTING_POINT.to_owned());
+        let distances = valves
+            .iter()
+            .map(|from| {
+                let reached = search::bfs(adjacencies, from.to_owned());
+                valves
+                    .iter()
+                    .map(|to| {
+                        reached
+                            .get(to)
+                            .ok_or(format!("no tunnels lead from {} to {}", from, to))
+                    })
+                    .collect::<Result<Vec<usize>, String>>()
+            })
+            .collect::<Result<Vec<Vec<usize>>, String>>()?;
+        Ok(Network { rates, distances })
+    }
+}
+
+pub struct Volcano {
+    network: Network,
+    minutes: usize,
+    minutes_with_elephant: usize,
+}
+
+pub struct Day16 {}
+impl AoCProblem for Day16 {
+    fn name(&self) -> String {
+        "day-16".to_owned()
+    }
+}
+impl Solution for Day16 {
+    type Parsed = Volcano;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let adjacencies = input
+            .parse_lines(|l| AdjacencyList::try_from(l))?
+            .into_iter()
+            .reduce(|mut p, n| {
+                p.merge(&n);
+                p
+            })
+            .ok_or_else(|| AocError::parse_file(input.path(), "expected at least one valve"))?;
+        let network =
+            Network::try_from(&adjacencies).map_err(|e| AocError::parse_file(input.path(), e))?;
+        Ok(Volcano {
+            network,
+            minutes: MINUTES.get(input)?,
+            minutes_with_elephant: MINUTES_WITH_ELEPHANT.get(input)?,
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![MINUTES.info(), MINUTES_WITH_ELEPHANT.info()]
+    }
+
+    fn part_one(&self, volcano: &Self::Parsed) -> AocResult<Answer> {
+        let network = &volcano.network;
+        let mut memo = HashMap::new();
+        Ok(network
+            .max_pressure(network.start(), volcano.minutes, 0, &mut memo)
+            .into())
+    }
+
+    fn part_two(&self, volcano: &Self::Parsed) -> AocResult<Answer> {
+        let network = &volcano.network;
+        // You and the elephant each open a different set of valves, so the best plan pairs up
+        // the best runs over disjoint sets
+        let mut best = HashMap::new();
+        network.explore(
+            network.start(),
+            volcano.minutes_with_elephant,
+            0,
+            0,
+            &mut best,
+        );
+        let mut by_pressure = best.into_iter().collect::<Vec<(u64, usize)>>();
+        by_pressure.sort_by_key(|(_, pressure)| Reverse(*pressure));
+
+        let mut most_pressure = 0;
+        for (i, (mine, my_pressure)) in by_pressure.iter().enumerate() {
+            // Every later pairing releases at most twice this
+            if my_pressure * 2 <= most_pressure {
+                break;
+            }
+            for (theirs, their_pressure) in by_pressure[i..].iter() {
+                if my_pressure + their_pressure <= most_pressure {
+                    break;
+                }
+                if mine & theirs == 0 {
+                    most_pressure = my_pressure + their_pressure;
+                    break;
+                }
+            }
+        }
+        Ok(most_pressure.into())
+    }
+}
@@ -0,0 +1,629 @@
+use shared::{
+    cycle::{self, Cycle},
+    Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution,
+};
+
+// How many rocks to drop for each part
+const PART1_ROCKS: Param<usize> = Param::new("part1_rocks", 2022);
+const PART2_ROCKS: Param<usize> = Param::new("part2_rocks", 1000000000000);
+// The chamber they fall in: how wide it is, and where each rock appears
+const WIDTH: Param<usize> = Param::new("width", 7);
+const SPAWN_LEFT: Param<usize> = Param::new("spawn_left", 2);
+const SPAWN_GAP: Param<usize> = Param::new("spawn_gap", 3);
+
+// The puzzle's rocks, in the order they fall
+const ROCKS: &str = "####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##";
+
+// A rock, one bitmask per row from the bottom up. Bit `i` is set if the rock covers column `i`,
+// counting from the left.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Shape {
+    rows: Vec<u64>,
+    width: usize,
+}
+impl Shape {
+    // Every shape in `input`, drawn with '#' and '.' as in the puzzle and separated by blank lines
+    pub fn parse_all(input: &Input) -> AocResult<Vec<Shape>> {
+        let shapes = input
+            .blocks()
+            .map(|block| {
+                Shape::try_from(block.text)
+                    .map_err(|e| AocError::parse(input.path(), block.line, e))
+            })
+            .collect::<AocResult<Vec<_>>>()?;
+        if shapes.is_empty() {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected at least one shape",
+            ));
+        }
+        Ok(shapes)
+    }
+}
+impl TryFrom<&str> for Shape {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let lines = value.lines().map(|l| l.trim()).collect::<Vec<_>>();
+        let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        if width > 64 {
+            return Err(format!(
+                "shapes can be at most 64 columns wide, found {}",
+                width
+            ));
+        }
+        // Drawn top down, stored bottom up
+        let rows = lines
+            .iter()
+            .rev()
+            .map(|line| {
+                line.chars()
+                    .enumerate()
+                    .try_fold(0u64, |row, (i, c)| match c {
+                        '#' => Ok(row | 1 << i),
+                        '.' => Ok(row),
+                        _ => Err(format!("expected '#' or '.' in a shape, found '{}'", c)),
+                    })
+            })
+            .collect::<Result<Vec<u64>, String>>()?;
+        if rows.is_empty() || rows.contains(&0) {
+            return Err(format!(
+                "expected every row of a shape to have rock in it:\n{}",
+                value
+            ));
+        }
+        Ok(Shape { rows, width })
+    }
+}
+
+// Where the rocks fall: how wide the chamber is, which rocks fall in it, and where each one
+// appears
+#[derive(Clone, Debug)]
+pub struct Chamber {
+    width: usize,
+    shapes: Vec<Shape>,
+    // Columns between the left wall and a new rock's left edge
+    spawn_left: usize,
+    // Empty rows between the highest rock and a new rock's bottom edge
+    spawn_gap: usize,
+}
+impl Chamber {
+    pub fn new(
+        width: usize,
+        shapes: Vec<Shape>,
+        spawn_left: usize,
+        spawn_gap: usize,
+    ) -> Result<Chamber, String> {
+        if !(1..=64).contains(&width) {
+            return Err(format!(
+                "chambers can be 1 to 64 columns wide, not {}",
+                width
+            ));
+        }
+        if let Some(shape) = shapes.iter().find(|s| spawn_left + s.width > width) {
+            return Err(format!(
+                "a shape {} wide can't appear {} from the left of a chamber {} wide",
+                shape.width, spawn_left, width
+            ));
+        }
+        if shapes.is_empty() {
+            return Err("expected at least one shape".to_owned());
+        }
+        Ok(Chamber {
+            width,
+            shapes,
+            spawn_left,
+            spawn_gap,
+        })
+    }
+
+    // The rightmost column, as a mask
+    fn right_wall(&self) -> u64 {
+        1 << (self.width - 1)
+    }
+
+    // Every column, as a mask
+    fn full(&self) -> u64 {
+        u64::MAX >> (64 - self.width)
+    }
+}
+impl Default for Chamber {
+    // The puzzle's chamber: seven wide, with rocks appearing two from the left and three up
+    fn default() -> Self {
+        let shapes = Shape::parse_all(&Input::from_text("rocks", ROCKS))
+            .expect("Should parse the puzzle's rocks");
+        Chamber::new(7, shapes, 2, 3).expect("Should fit the puzzle's rocks")
+    }
+}
+
+// Try to apply a shift operation, respecting the walls
+fn shift(rows: &[u64], op: Op, chamber: &Chamber) -> Vec<u64> {
+    match op {
+        Op::Left if rows.iter().all(|row| row & 1 == 0) => {
+            rows.iter().map(|row| row >> 1).collect()
+        }
+        Op::Right if rows.iter().all(|row| row & chamber.right_wall() == 0) => {
+            rows.iter().map(|row| row << 1).collect()
+        }
+        _ => rows.to_vec(),
+    }
+}
+
+// How far below the top of the tower a falling rock is taken to be able to reach. Open space any
+// deeper, like a column no rock ever fills, counts as solid, so that the tower's surface can repeat
+// and the rows under it can be dropped.
+const MAX_DEPTH: usize = 128;
+
+// The settled rock, one bitmask per row from the floor up, along with where we are in the jet
+// pattern and the order of rocks. The top row always has rock in it. Rows no rock can reach any
+// more are dropped, so only the part of the tower near the surface is kept.
+#[derive(Clone)]
+struct Grid<'a> {
+    rows: Vec<u64>,
+    // How many rows were dropped from the bottom of `rows`
+    pruned: usize,
+    // For each column, how far below the top a falling rock could reach
+    surface: Vec<usize>,
+    chamber: &'a Chamber,
+    jets: &'a [Op],
+    // Index of the next jet and the next rock
+    jet: usize,
+    rock: usize,
+    // How many rocks have come to rest
+    rocks: usize,
+    // For each row, the number of the last rock to come to rest in it and the index of the jet
+    // that last pushed that rock
+    settled_by: Vec<(usize, usize)>,
+}
+impl<'a> Grid<'a> {
+    fn new(chamber: &'a Chamber, jets: &'a [Op]) -> Grid<'a> {
+        Grid {
+            rows: vec![],
+            pruned: 0,
+            surface: vec![0; chamber.width],
+            chamber,
+            jets,
+            jet: 0,
+            rock: 0,
+            rocks: 0,
+            settled_by: vec![],
+        }
+    }
+
+    fn highest(&self) -> usize {
+        self.pruned + self.rows.len()
+    }
+
+    // The row at height `y`. Pruned rows are out of reach, so they count as solid.
+    fn row(&self, y: usize) -> u64 {
+        match y.checked_sub(self.pruned) {
+            Some(i) => self.rows.get(i).copied().unwrap_or(0),
+            None => self.chamber.full(),
+        }
+    }
+
+    fn collides(&self, origin: usize, bitfield: &[u64]) -> bool {
+        // Returns true if a given bitfield collides with an existing shape
+        bitfield
+            .iter()
+            .enumerate()
+            .any(|(i, shape_row)| self.row(i + origin) & shape_row != 0)
+    }
+
+    // Everything that decides how the rest of the tower grows
+    fn key(&self) -> (usize, usize, Vec<usize>) {
+        (self.jet, self.rock, self.surface.clone())
+    }
+
+    // How many rocks to drop looking for the tower to repeat: a few passes over every pairing of
+    // jet and rock, allowing for the surface to take shape first
+    fn cycle_search_limit(&self) -> usize {
+        let pairings = self.jets.len() * self.chamber.shapes.len();
+        CYCLE_SEARCH_LAPS * (pairings + MAX_DEPTH)
+    }
+
+    // Where the tower starts repeating, if it does within the search limit
+    fn find_cycle(&self) -> Option<Cycle> {
+        cycle::brent(self, Grid::round, Grid::key, self.cycle_search_limit())
+    }
+
+    // Drop the next rock until it comes to rest
+    fn round(&mut self) {
+        self.round_with(|_, _, _| {});
+    }
+
+    // Drop the next rock, showing `observe` the grid, the rock, and the height of its bottom when
+    // it appears and after each jet and fall
+    fn round_with<F>(&mut self, mut observe: F)
+    where
+        F: FnMut(&Grid, &[u64], usize),
+    {
+        let mut shape_origin = self.highest() + self.chamber.spawn_gap;
+        let mut shape = self.chamber.shapes[self.rock]
+            .rows
+            .iter()
+            .map(|row| row << self.chamber.spawn_left)
+            .collect::<Vec<u64>>();
+        self.rock = (self.rock + 1) % self.chamber.shapes.len();
+        observe(self, &shape, shape_origin);
+        let mut last_jet;
+        loop {
+            // Get an op, transform shape, and check for overflow/collision
+            let op = self.jets[self.jet];
+            last_jet = self.jet;
+            self.jet = (self.jet + 1) % self.jets.len();
+            let shifted_shape = shift(&shape, op, self.chamber);
+            if !self.collides(shape_origin, &shifted_shape) {
+                shape = shifted_shape;
+            }
+            // Reduce origin, check for overflow/collision
+            if shape_origin == 0 || self.collides(shape_origin - 1, &shape) {
+                break;
+            } else {
+                shape_origin -= 1;
+            }
+            observe(self, &shape, shape_origin);
+        }
+        self.rocks += 1;
+        self.render_shape(&shape, shape_origin, last_jet);
+        self.prune();
+    }
+
+    fn render_shape(&mut self, shape: &[u64], origin: usize, jet: usize) {
+        let origin = origin - self.pruned;
+        if self.rows.len() < origin + shape.len() {
+            self.rows.resize(origin + shape.len(), 0);
+            self.settled_by.resize(origin + shape.len(), (0, 0));
+        }
+        for (i, shape_row) in shape.iter().enumerate() {
+            self.rows[i + origin] |= shape_row;
+            self.settled_by[i + origin] = (self.rocks, jet);
+        }
+    }
+
+    // Work out which empty cells a falling rock could still get to, moving down and sideways
+    // from above the tower. That gives the surface, and everything more than a row below the
+    // lowest of those cells can be dropped.
+    fn prune(&mut self) {
+        let full = self.chamber.full();
+        let mut reachable = full;
+        let mut lowest = self.rows.len();
+        self.surface = vec![0; self.chamber.width];
+        for i in (self.rows.len().saturating_sub(MAX_DEPTH)..self.rows.len()).rev() {
+            let open = !self.rows[i] & full;
+            reachable &= open;
+            // Spread sideways until there's nowhere new to go
+            loop {
+                let spread = (reachable | reachable << 1 | reachable >> 1) & open;
+                if spread == reachable {
+                    break;
+                }
+                reachable = spread;
+            }
+            if reachable == 0 {
+                break;
+            }
+            lowest = i;
+            for (col, depth) in self.surface.iter_mut().enumerate() {
+                if reachable & 1 << col != 0 {
+                    *depth = self.rows.len() - i;
+                }
+            }
+        }
+        // A rock resting in the lowest reachable row still needs the row under it
+        let keep_from = lowest.saturating_sub(1);
+        self.rows.drain(..keep_from);
+        self.settled_by.drain(..keep_from);
+        self.pruned += keep_from;
+    }
+
+    // Draw up to `window` rows down from the top of the tower, or of the falling rock if there is
+    // one. The falling rock (its rows and the height of its bottom) is drawn with '@', and each row
+    // is labelled with the rock that last came to rest in it and the jet that put it there.
+    fn render(&self, window: usize, falling: Option<(&[u64], usize)>) -> String {
+        let top = match falling {
+            Some((shape, origin)) => self.highest().max(origin + shape.len()),
+            None => self.highest(),
+        };
+        let bottom = top.saturating_sub(window).max(self.pruned);
+        let mut lines = vec![];
+        for y in (bottom..top).rev() {
+            let settled = self.row(y);
+            let rock = match falling {
+                Some((shape, origin)) if (origin..origin + shape.len()).contains(&y) => {
+                    shape[y - origin]
+                }
+                _ => 0,
+            };
+            let cells = (0..self.chamber.width)
+                .map(|col| {
+                    if rock & 1 << col != 0 {
+                        '@'
+                    } else if settled & 1 << col != 0 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            let mut line = format!("|{}|", cells);
+            if let Some((rock, jet)) = self.settled_by.get(y - self.pruned) {
+                line.push_str(&format!("  rock {}, jet {}", rock, jet));
+            }
+            lines.push(line);
+        }
+        if bottom == 0 {
+            lines.push(format!("+{}+", "-".repeat(self.chamber.width)));
+        } else {
+            lines.push(format!("({} rows below)", bottom));
+        }
+        lines.join("\n")
+    }
+}
+#[derive(Clone, Copy, Debug)]
+pub enum Op {
+    Left,
+    Right,
+}
+impl TryFrom<char> for Op {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(Op::Left),
+            '>' => Ok(Op::Right),
+            _ => Err("Unrecognized operation".to_owned()),
+        }
+    }
+}
+
+pub struct Jets {
+    pattern: Vec<Op>,
+    chamber: Chamber,
+    part1_rocks: usize,
+    part2_rocks: usize,
+}
+impl Jets {
+    /// The same jets and chamber, with `shapes` falling in place of the puzzle's rocks
+    pub fn with_shapes(self, shapes: Vec<Shape>) -> Result<Jets, String> {
+        let chamber = Chamber::new(
+            self.chamber.width,
+            shapes,
+            self.chamber.spawn_left,
+            self.chamber.spawn_gap,
+        )?;
+        Ok(Jets { chamber, ..self })
+    }
+}
+
+/// How tall the tower is once `rocks` rocks have fallen in `chamber`, pushed around by `jets`.
+/// Too many rocks to drop one by one are only simulated until the tower starts repeating, which
+/// is an error if it never does.
+pub fn tower_height(chamber: &Chamber, jets: &[Op], rocks: usize) -> Result<usize, String> {
+    if jets.is_empty() {
+        return Err("expected at least one jet".to_owned());
+    }
+    let start = Grid::new(chamber, jets);
+    // Looking for a cycle would take about as long as just dropping them
+    if rocks <= start.cycle_search_limit() {
+        let mut grid = start;
+        for _ in 0..rocks {
+            grid.round();
+        }
+        return Ok(grid.highest());
+    }
+    let cycle = start
+        .find_cycle()
+        .ok_or("the tower never settles into a repeating pattern")?;
+    let height = cycle.extrapolate(&start, Grid::round, |grid| grid.highest() as i64, rocks);
+    Ok(height as usize)
+}
+
+// How many times to go through every pairing of jet and rock looking for the tower to repeat
+const CYCLE_SEARCH_LAPS: usize = 4;
+
+// How many rows of the tower to show when tracing
+const TRACE_WINDOW: usize = 12;
+
+pub struct Day17 {}
+impl Day17 {
+    /// Every step of the first `rocks` rocks falling, drawn the way the puzzle draws them
+    pub fn trace(&self, input: &Input, rocks: usize) -> AocResult<String> {
+        let jets = self.parse(input)?;
+        let mut grid = Grid::new(&jets.chamber, &jets.pattern);
+        let mut steps = vec![];
+        for _ in 0..rocks {
+            grid.round_with(|grid, shape, origin| {
+                steps.push(grid.render(TRACE_WINDOW, Some((shape, origin))));
+            });
+        }
+        steps.push(grid.render(TRACE_WINDOW, None));
+        Ok(steps.join("\n\n") + "\n")
+    }
+
+    /// The top of the tower where it starts repeating, and how it repeats
+    pub fn cycle_start(&self, input: &Input) -> AocResult<String> {
+        let jets = self.parse(input)?;
+        let mut grid = Grid::new(&jets.chamber, &jets.pattern);
+        let cycle = grid.find_cycle().ok_or_else(|| {
+            AocError::invariant("The tower never settles into a repeating pattern")
+        })?;
+        for _ in 0..cycle.prefix {
+            grid.round();
+        }
+        let start_height = grid.highest();
+        // Show every row a rock could still reach
+        let window = grid.surface.iter().max().map_or(0, |depth| depth + 1);
+        let mut lap = grid.clone();
+        for _ in 0..cycle.period {
+            lap.round();
+        }
+        Ok(format!(
+            "After {} rocks the tower repeats every {} rocks, growing {} rows each time.\n\
+             Next jet {}, next rock shape {}, surface {:?}:\n{}\n",
+            cycle.prefix,
+            cycle.period,
+            lap.highest() - start_height,
+            grid.jet,
+            grid.rock,
+            grid.surface,
+            grid.render(window.max(TRACE_WINDOW), None)
+        ))
+    }
+}
+impl AoCProblem for Day17 {
+    fn name(&self) -> String {
+        "day-17".to_owned()
+    }
+}
+impl Solution for Day17 {
+    type Parsed = Jets;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| AocError::parse_file(input.path(), "expected a line of jets"))?;
+        let pattern = line
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                Op::try_from(c).map_err(|e| AocError::parse(input.path(), 1, e).at_column(i + 1))
+            })
+            .collect::<AocResult<Vec<Op>>>()?;
+        if pattern.is_empty() {
+            return Err(AocError::parse(
+                input.path(),
+                1,
+                "expected at least one jet",
+            ));
+        }
+        let shapes = Shape::parse_all(&Input::from_text("rocks", ROCKS))
+            .expect("Should parse the puzzle's rocks");
+        let chamber = Chamber::new(
+            WIDTH.get(input)?,
+            shapes,
+            SPAWN_LEFT.get(input)?,
+            SPAWN_GAP.get(input)?,
+        )
+        .map_err(|e| AocError::parse_file(input.path(), e))?;
+        Ok(Jets {
+            pattern,
+            chamber,
+            part1_rocks: PART1_ROCKS.get(input)?,
+            part2_rocks: PART2_ROCKS.get(input)?,
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![
+            PART1_ROCKS.info(),
+            PART2_ROCKS.info(),
+            WIDTH.info(),
+            SPAWN_LEFT.info(),
+            SPAWN_GAP.info(),
+        ]
+    }
+
+    fn part_one(&self, jets: &Self::Parsed) -> AocResult<Answer> {
+        let height = tower_height(&jets.chamber, &jets.pattern, jets.part1_rocks)
+            .map_err(AocError::invariant)?;
+        Ok(height.into())
+    }
+
+    fn part_two(&self, jets: &Self::Parsed) -> AocResult<Answer> {
+        let height = tower_height(&jets.chamber, &jets.pattern, jets.part2_rocks)
+            .map_err(AocError::invariant)?;
+        Ok(height.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drop_rocks<'a>(chamber: &'a Chamber, jets: &'a [Op], rocks: usize) -> Grid<'a> {
+        let mut grid = Grid::new(chamber, jets);
+        for _ in 0..rocks {
+            grid.round();
+        }
+        grid
+    }
+
+    #[test]
+    fn should_simulate_custom_chambers() {
+        let shapes = Shape::parse_all(&Input::from_text("shapes", "###\r\n\r\n#.\r\n##\r\n"))
+            .expect("Should parse shapes");
+        let chamber = Chamber::new(4, shapes, 1, 0).expect("Should fit the shapes");
+        let grid = drop_rocks(&chamber, &[Op::Right], 3);
+        assert_eq!(
+            grid.render(10, None),
+            "|.###|  rock 3, jet 0\n|..#.|  rock 2, jet 0\n|..##|  rock 2, jet 0\n|.###|  rock 1, jet 0\n+----+"
+        );
+        let jets = [Op::Right, Op::Left, Op::Left];
+        for rocks in [3, 500] {
+            assert_eq!(
+                tower_height(&chamber, &jets, rocks),
+                Ok(drop_rocks(&chamber, &jets, rocks).highest())
+            );
+        }
+        assert!(tower_height(&chamber, &[], 3).is_err());
+
+        // Columns that never fill don't stop the tower repeating
+        let chamber = Chamber::default();
+        assert_eq!(
+            tower_height(&chamber, &[Op::Left], 2022),
+            Ok(drop_rocks(&chamber, &[Op::Left], 2022).highest())
+        );
+        assert_eq!(
+            tower_height(&chamber, &[Op::Left], 1000000000000),
+            Ok(2200000000000)
+        );
+
+        // Rocks can reach the far wall of the widest chamber
+        let shapes =
+            Shape::parse_all(&Input::from_text("shapes", "####")).expect("Should parse shapes");
+        let chamber = Chamber::new(64, shapes, 2, 60).expect("Should fit the shapes");
+        let grid = drop_rocks(&chamber, &[Op::Right], 1);
+        assert_eq!(grid.rows, vec![0xF << 60]);
+
+        // Only the rows near the surface are kept
+        let jets = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"
+            .chars()
+            .map(Op::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Should parse jets");
+        let chamber = Chamber::default();
+        let grid = drop_rocks(&chamber, &jets, 2022);
+        assert_eq!(grid.highest(), 3068);
+        assert!(grid.rows.len() < 100);
+        assert_eq!(grid.surface.len(), 7);
+
+        // Even when some columns never fill
+        let grid = drop_rocks(&chamber, &[Op::Left], 2022);
+        assert!(grid.rows.len() <= MAX_DEPTH + 1);
+        assert!(grid.surface.iter().all(|depth| *depth <= MAX_DEPTH));
+
+        assert!(Chamber::new(65, vec![], 2, 3).is_err());
+        let shapes = Shape::parse_all(&Input::from_text("rocks", ROCKS))
+            .expect("Should parse the puzzle's rocks");
+        assert!(Chamber::new(5, shapes, 2, 3).is_err());
+        assert!(Shape::parse_all(&Input::from_text("shapes", "#x#")).is_err());
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::HashSet;
+
+use shared::{geom::Point3, Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+fn parse_line(line: &str) -> Result<Coordinate, String> {
+    let parts = line
+        .trim()
+        .split(',')
+        .map(|p| {
+            p.parse::<i64>()
+                .map_err(|e| format!("invalid coordinate '{}': {}", p, e))
+        })
+        .collect::<Result<Vec<i64>, String>>()?;
+    match parts[..] {
+        [x, y, z] => Ok(Coordinate::new(x, y, z)),
+        _ => Err("expected 'x,y,z'".to_owned()),
+    }
+}
+
+type Coordinate = Point3<i64>;
+
+fn exposed_faces(origins: &HashSet<Coordinate>) -> Vec<(Coordinate, Coordinate)> {
+    origins
+        .iter()
+        .flat_map(|o| {
+            o.neighbors6()
+                .into_iter()
+                .filter(|n| !origins.contains(n))
+                .map(|n| (*o, n))
+        })
+        .collect::<Vec<_>>()
+}
+
+/// The smallest and largest coordinate along each axis
+fn bounds<'a, I>(origins: I) -> (Coordinate, Coordinate)
+where
+    I: IntoIterator<Item = &'a Coordinate>,
+{
+    origins
+        .into_iter()
+        .fold(None, |bounds: Option<(Coordinate, Coordinate)>, o| {
+            Some(match bounds {
+                Some((min, max)) => (min.component_min(o), max.component_max(o)),
+                None => (*o, *o),
+            })
+        })
+        .expect("Should have at least one coordinate")
+}
+
+fn shell_2d(min_0: i64, min_1: i64, max_0: i64, max_1: i64) -> Vec<(i64, i64)> {
+    let mut res = vec![];
+    for c0 in min_0 - 1..max_0 + 2 {
+        for c1 in min_1 - 1..max_1 + 2 {
+            res.push((c0, c1));
+        }
+    }
+    res
+}
+
+fn shell(origins: &HashSet<Coordinate>) -> Vec<Coordinate> {
+    let (min, max) = bounds(origins);
+    let (min_x, min_y, min_z, max_x, max_y, max_z) = (min.x, min.y, min.z, max.x, max.y, max.z);
+    vec![
+        shell_2d(min_x, min_y, max_x, max_y)
+            .into_iter()
+            .map(|o| Coordinate::new(o.0, o.1, min_z - 1))
+            .collect::<Vec<Coordinate>>(),
+        shell_2d(min_x, min_z, max_x, max_z)
+            .into_iter()
+            .map(|o| Coordinate::new(o.0, min_y - 1, o.1))
+            .collect::<Vec<Coordinate>>(),
+        shell_2d(min_y, min_z, max_y, max_z)
+            .into_iter()
+            .map(|o| Coordinate::new(min_x - 1, o.0, o.1))
+            .collect::<Vec<Coordinate>>(),
+        shell_2d(min_x, min_y, max_x, max_y)
+            .into_iter()
+            .map(|o| Coordinate::new(o.0, o.1, max_z + 1))
+            .collect::<Vec<Coordinate>>(),
+        shell_2d(min_x, min_z, max_x, max_z)
+            .into_iter()
+            .map(|o| Coordinate::new(o.0, max_y + 1, o.1))
+            .collect::<Vec<Coordinate>>(),
+        shell_2d(min_y, min_z, max_y, max_z)
+            .into_iter()
+            .map(|o| Coordinate::new(max_x + 1, o.0, o.1))
+            .collect::<Vec<Coordinate>>(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<HashSet<Coordinate>>()
+    .into_iter()
+    .collect::<Vec<Coordinate>>()
+}
+
+fn flood(shell: Vec<Coordinate>, origins: HashSet<Coordinate>) -> HashSet<Coordinate> {
+    let (min, max) = bounds(&shell);
+    let mut res = HashSet::new();
+    res.extend(shell.clone());
+    res.extend(origins.iter());
+    let mut candidates = shell
+        .iter()
+        .flat_map(|c| c.neighbors6())
+        .filter(|c| {
+            c.x < max.x
+                && c.x > min.x
+                && c.y < max.y
+                && c.y > min.y
+                && c.z < max.z
+                && c.z > min.z
+                && !res.contains(c)
+        })
+        .collect::<Vec<_>>();
+    loop {
+        if candidates.is_empty() {
+            break;
+        }
+        res.extend(&candidates);
+        let new_candidates = candidates
+            .iter()
+            .flat_map(|c| c.neighbors6())
+            .filter(|c| !res.contains(c))
+            .collect::<Vec<_>>();
+        candidates = new_candidates;
+    }
+    res.into_iter()
+        .filter(|c| !origins.contains(c))
+        .collect::<HashSet<_>>()
+}
+
+pub struct Day18 {}
+impl AoCProblem for Day18 {
+    fn name(&self) -> String {
+        "day-18".to_owned()
+    }
+}
+impl Solution for Day18 {
+    type Parsed = HashSet<Coordinate>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let origins = input
+            .parse_lines(parse_line)?
+            .into_iter()
+            .collect::<HashSet<Coordinate>>();
+        if origins.is_empty() {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected at least one cube",
+            ));
+        }
+        Ok(origins)
+    }
+
+    fn part_one(&self, origins: &Self::Parsed) -> AocResult<Answer> {
+        Ok(exposed_faces(origins).len().into())
+    }
+
+    fn part_two(&self, origins: &Self::Parsed) -> AocResult<Answer> {
+        let exposed_faces = exposed_faces(origins);
+
+        let shell = shell(origins);
+        let flooded = flood(shell, origins.clone());
+        let exterior_exposed_faces = exposed_faces
+            .iter()
+            .filter(|(_origin, face)| flooded.contains(face))
+            .collect::<Vec<_>>();
+        Ok(exterior_exposed_faces.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn exposed_faces_should() {
+        let mut input = HashSet::new();
+        input.insert(Coordinate::new(1, 1, 1));
+        input.insert(Coordinate::new(2, 1, 1));
+        input.insert(Coordinate::new(1, 1, 2));
+
+        let res = exposed_faces(&input);
+        assert_eq!(14, res.len());
+
+        input.insert(Coordinate::new(2, 1, 2));
+        let res = exposed_faces(&input);
+        assert_eq!(16, res.len());
+    }
+
+    #[test]
+    fn parse_line_should_keep_values() {
+        let input = "1,1,0";
+        let res = parse_line(input).expect("Should parse");
+        assert_eq!(res, Coordinate::new(1, 1, 0));
+    }
+
+    #[test]
+    fn shell_should_encase_origin() {
+        let mut input = HashSet::new();
+        input.insert(Coordinate::new(0, 0, 0));
+        let res = shell(&input);
+        assert_eq!(26, res.len());
+        assert!(res.contains(&Coordinate::new(-1, -1, -1)));
+        assert_eq!(26, flood(res, input).len());
+    }
+
+    #[test]
+    fn shell_should_make_face() {
+        let res = shell_2d(2, 2, 2, 2);
+        assert_eq!(9, res.len());
+        assert!(res.contains(&(1, 1)));
+        assert!(res.contains(&(1, 2)));
+        assert!(res.contains(&(1, 3)));
+        assert!(res.contains(&(2, 1)));
+        assert!(res.contains(&(2, 2)));
+        assert!(res.contains(&(2, 3)));
+        assert!(res.contains(&(3, 1)));
+        assert!(res.contains(&(3, 2)));
+        assert!(res.contains(&(3, 3)));
+
+        let res = shell_2d(2, 2, 3, 3);
+        assert_eq!(16, res.len());
+
+        let res = shell_2d(2, 2, 3, 2);
+        assert_eq!(12, res.len());
+    }
+
+    #[test]
+    fn shell_should_encase() {
+        let mut input = HashSet::new();
+        input.insert(Coordinate::new(2, 2, 2));
+        let res = shell(&input);
+
+        assert_eq!(26, res.len());
+
+        assert!(res.contains(&Coordinate::new(1, 1, 1)));
+        assert!(res.contains(&Coordinate::new(1, 1, 2)));
+        assert!(res.contains(&Coordinate::new(1, 1, 3)));
+        assert!(res.contains(&Coordinate::new(1, 2, 1)));
+        assert!(res.contains(&Coordinate::new(1, 2, 2)));
+        assert!(res.contains(&Coordinate::new(1, 2, 3)));
+        assert!(res.contains(&Coordinate::new(1, 3, 1)));
+        assert!(res.contains(&Coordinate::new(1, 3, 2)));
+        assert!(res.contains(&Coordinate::new(1, 3, 3)));
+
+        assert!(res.contains(&Coordinate::new(1, 1, 1)));
+        assert!(res.contains(&Coordinate::new(1, 1, 2)));
+        assert!(res.contains(&Coordinate::new(1, 1, 3)));
+        assert!(res.contains(&Coordinate::new(2, 1, 1)));
+        assert!(res.contains(&Coordinate::new(2, 1, 2)));
+        assert!(res.contains(&Coordinate::new(2, 1, 3)));
+        assert!(res.contains(&Coordinate::new(3, 1, 1)));
+        assert!(res.contains(&Coordinate::new(3, 1, 2)));
+        assert!(res.contains(&Coordinate::new(3, 1, 3)));
+
+        assert!(res.contains(&Coordinate::new(1, 1, 1)));
+        assert!(res.contains(&Coordinate::new(1, 2, 1)));
+        assert!(res.contains(&Coordinate::new(1, 3, 1)));
+        assert!(res.contains(&Coordinate::new(2, 1, 1)));
+        assert!(res.contains(&Coordinate::new(2, 2, 1)));
+        assert!(res.contains(&Coordinate::new(2, 3, 1)));
+        assert!(res.contains(&Coordinate::new(3, 1, 1)));
+        assert!(res.contains(&Coordinate::new(3, 2, 1)));
+        assert!(res.contains(&Coordinate::new(3, 3, 1)));
+
+        assert!(res.contains(&Coordinate::new(2, 1, 1)));
+        assert!(res.contains(&Coordinate::new(2, 1, 2)));
+        assert!(res.contains(&Coordinate::new(2, 1, 3)));
+        assert!(res.contains(&Coordinate::new(2, 2, 1)));
+        assert!(res.contains(&Coordinate::new(2, 2, 3)));
+        assert!(res.contains(&Coordinate::new(2, 3, 1)));
+        assert!(res.contains(&Coordinate::new(2, 3, 2)));
+        assert!(res.contains(&Coordinate::new(2, 3, 3)));
+
+        assert!(res.contains(&Coordinate::new(1, 2, 1)));
+        assert!(res.contains(&Coordinate::new(1, 2, 2)));
+        assert!(res.contains(&Coordinate::new(1, 2, 3)));
+        assert!(res.contains(&Coordinate::new(2, 2, 1)));
+        assert!(res.contains(&Coordinate::new(2, 2, 3)));
+        assert!(res.contains(&Coordinate::new(3, 2, 1)));
+        assert!(res.contains(&Coordinate::new(3, 2, 2)));
+        assert!(res.contains(&Coordinate::new(3, 2, 3)));
+
+        assert!(res.contains(&Coordinate::new(1, 1, 2)));
+        assert!(res.contains(&Coordinate::new(1, 2, 2)));
+        assert!(res.contains(&Coordinate::new(1, 3, 2)));
+        assert!(res.contains(&Coordinate::new(2, 1, 2)));
+        assert!(res.contains(&Coordinate::new(2, 3, 2)));
+        assert!(res.contains(&Coordinate::new(3, 1, 2)));
+        assert!(res.contains(&Coordinate::new(3, 2, 2)));
+        assert!(res.contains(&Coordinate::new(3, 3, 2)));
+
+        assert!(res.contains(&Coordinate::new(3, 1, 1)));
+        assert!(res.contains(&Coordinate::new(3, 1, 2)));
+        assert!(res.contains(&Coordinate::new(3, 1, 3)));
+        assert!(res.contains(&Coordinate::new(3, 2, 1)));
+        assert!(res.contains(&Coordinate::new(3, 2, 2)));
+        assert!(res.contains(&Coordinate::new(3, 2, 3)));
+        assert!(res.contains(&Coordinate::new(3, 3, 1)));
+        assert!(res.contains(&Coordinate::new(3, 3, 2)));
+        assert!(res.contains(&Coordinate::new(3, 3, 3)));
+
+        assert!(res.contains(&Coordinate::new(1, 3, 1)));
+        assert!(res.contains(&Coordinate::new(1, 3, 2)));
+        assert!(res.contains(&Coordinate::new(1, 3, 3)));
+        assert!(res.contains(&Coordinate::new(2, 3, 1)));
+        assert!(res.contains(&Coordinate::new(2, 3, 2)));
+        assert!(res.contains(&Coordinate::new(2, 3, 3)));
+        assert!(res.contains(&Coordinate::new(3, 3, 1)));
+        assert!(res.contains(&Coordinate::new(3, 3, 2)));
+        assert!(res.contains(&Coordinate::new(3, 3, 3)));
+
+        assert!(res.contains(&Coordinate::new(1, 1, 3)));
+        assert!(res.contains(&Coordinate::new(1, 2, 3)));
+        assert!(res.contains(&Coordinate::new(1, 3, 3)));
+        assert!(res.contains(&Coordinate::new(2, 1, 3)));
+        assert!(res.contains(&Coordinate::new(2, 2, 3)));
+        assert!(res.contains(&Coordinate::new(2, 3, 3)));
+        assert!(res.contains(&Coordinate::new(3, 1, 3)));
+        assert!(res.contains(&Coordinate::new(3, 2, 3)));
+        assert!(res.contains(&Coordinate::new(3, 3, 3)));
+
+        let mut input = HashSet::new();
+        input.insert(Coordinate::new(2, 2, 2));
+        input.insert(Coordinate::new(3, 2, 2));
+        let res = shell(&input);
+
+        assert_eq!(34, res.len());
+
+        let mut input = HashSet::new();
+        input.insert(Coordinate::new(2, 2, 2));
+        input.insert(Coordinate::new(3, 2, 2));
+        input.insert(Coordinate::new(2, 2, 3));
+        let res = shell(&input);
+
+        // Missing 3 for the actual blocks, plus one pocket because of the L-shaped configuration
+        assert_eq!(44, res.len());
+    }
+
+    #[test]
+    fn flood_should_fill_l() {
+        let mut input = HashSet::new();
+        input.insert(Coordinate::new(2, 2, 2));
+        input.insert(Coordinate::new(3, 2, 2));
+        input.insert(Coordinate::new(2, 2, 3));
+        let outer_shell = shell(&input);
+        let flooded = flood(outer_shell, input);
+        assert_eq!(45, flooded.len());
+    }
+}
@@ -0,0 +1,337 @@
+use std::collections::BinaryHeap;
+
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution};
+
+// How long each part has to crack geodes, and how many blueprints part two looks at
+const PART1_MINUTES: Param<usize> = Param::new("part1_minutes", 24);
+const PART2_MINUTES: Param<usize> = Param::new("part2_minutes", 32);
+const PART2_BLUEPRINTS: Param<usize> = Param::new("part2_blueprints", 3);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BotType {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    time: usize,
+
+    // Bots
+    ore_bots: usize,
+    clay_bots: usize,
+    obsidian_bots: usize,
+    geode_bots: usize,
+
+    // Resources
+    ore: usize,
+    clay: usize,
+    obsidian: usize,
+    geodes: usize,
+}
+impl State {
+    fn add_bot(&mut self, bot: BotType, blueprint: &Blueprint) {
+        match bot {
+            BotType::Ore => {
+                self.ore_bots += 1;
+                self.ore -= blueprint.ore_robot_ore_cost;
+            }
+            BotType::Clay => {
+                self.clay_bots += 1;
+                self.ore -= blueprint.clay_robot_ore_cost;
+            }
+            BotType::Obsidian => {
+                self.obsidian_bots += 1;
+                self.ore -= blueprint.obsidian_robot_ore_cost;
+                self.clay -= blueprint.obsidian_robot_clay_cost;
+            }
+            BotType::Geode => {
+                self.geode_bots += 1;
+                self.ore -= blueprint.geode_robot_ore_cost;
+                self.obsidian -= blueprint.geode_robot_obsidian_cost;
+            }
+        }
+    }
+
+    fn can_build(&self, bot: BotType, blueprint: &Blueprint) -> bool {
+        match bot {
+            BotType::Ore => self.ore >= blueprint.ore_robot_ore_cost,
+            BotType::Clay => self.ore >= blueprint.clay_robot_ore_cost,
+            BotType::Obsidian => {
+                self.ore >= blueprint.obsidian_robot_ore_cost
+                    && self.clay >= blueprint.obsidian_robot_clay_cost
+            }
+            BotType::Geode => {
+                self.ore >= blueprint.geode_robot_ore_cost
+                    && self.obsidian >= blueprint.geode_robot_obsidian_cost
+            }
+        }
+    }
+
+    fn initial_state(time: usize) -> Self {
+        State {
+            time,
+
+            ore_bots: 1,
+            clay_bots: 0,
+            obsidian_bots: 0,
+            geode_bots: 0,
+
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geodes: 0,
+        }
+    }
+
+    /// Run simulation until the specified bot type is built, or until the simulation runs out of time
+    fn simulate_until_can_build_bot(&self, blueprint: &Blueprint, bot: &BotType) -> State {
+        // Short circuit for unbuildable configs
+        if (bot.eq(&BotType::Geode) && self.obsidian_bots < 1)
+            || (bot.eq(&BotType::Obsidian) && self.clay_bots < 1)
+        {
+            let mut new_state = self.clone();
+            new_state.time = 0;
+            return new_state;
+        }
+
+        // Given _enough_ time, we should be able to gather enough resources. Now the question becomes "Do we have enough time"
+        let mut new_state = self.clone();
+        while new_state.time > 0 {
+            new_state.time -= 1;
+            if new_state.can_build(*bot, blueprint) {
+                new_state.gather();
+                new_state.add_bot(*bot, blueprint);
+                break;
+            } else {
+                new_state.gather();
+            }
+        }
+
+        new_state
+    }
+
+    fn gather(&mut self) {
+        self.ore += self.ore_bots;
+        self.clay += self.clay_bots;
+        self.obsidian += self.obsidian_bots;
+        self.geodes += self.geode_bots;
+    }
+}
+
+struct Strategy {
+    blueprint: Blueprint,
+    state: State,
+}
+impl Strategy {
+    /// Computes an upper bound on the value (in total cracked geodes) of continuing to follow
+    /// this path. Note that this is a very loose estimate, used for pruning search space. All
+    /// options which even have the _potential_ to exceed the current maximum found will be fully
+    /// explored. It is fine to overestimate, which trades additional computation time for simplicity
+    fn compute_upper_bound_value(&self) -> usize {
+        let mut rounds_remaining = self.state.time;
+        let mut total_geodes = self.state.geodes;
+        let mut geode_bots = self.state.geode_bots;
+        let mut should_build = self.state.obsidian > 0;
+        while rounds_remaining > 0 {
+            // Gather
+            total_geodes += geode_bots;
+
+            // Build
+            if should_build {
+                geode_bots += 1;
+            } else {
+                should_build = !should_build;
+            }
+
+            rounds_remaining -= 1;
+        }
+
+        total_geodes
+    }
+
+    /// Given a current strategy state, generate potential bots to build next
+    fn expand(&self) -> Vec<BotType> {
+        let mut candidates = vec![BotType::Ore, BotType::Clay];
+        if self.state.clay_bots > 0 {
+            candidates.push(BotType::Obsidian);
+        }
+        if self.state.obsidian_bots > 0 {
+            candidates.push(BotType::Geode);
+        }
+        candidates
+    }
+}
+impl PartialEq for Strategy {
+    fn eq(&self, other: &Self) -> bool {
+        self.compute_upper_bound_value()
+            .eq(&other.compute_upper_bound_value())
+    }
+}
+impl Eq for Strategy {}
+impl PartialOrd for Strategy {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Strategy {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.compute_upper_bound_value()
+            .cmp(&other.compute_upper_bound_value())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Blueprint {
+    id: usize,
+    ore_robot_ore_cost: usize,
+    clay_robot_ore_cost: usize,
+    obsidian_robot_ore_cost: usize,
+    obsidian_robot_clay_cost: usize,
+    geode_robot_ore_cost: usize,
+    geode_robot_obsidian_cost: usize,
+}
+impl TryFrom<&str> for Blueprint {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parts = value.split(' ').collect::<Vec<_>>();
+        if parts.len() < 31 {
+            return Err("expected a full blueprint description".to_owned());
+        }
+        let id = parts[1]
+            .strip_suffix(':')
+            .ok_or("expected a trailing ':' after the blueprint id")?
+            .parse::<usize>()
+            .map_err(|e| format!("invalid id: {}", e))?;
+        let ore_robot_ore_cost = parts[6]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid ore_robot_ore_cost: {}", e))?;
+        let clay_robot_ore_cost = parts[12]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid clay_robot_ore_cost: {}", e))?;
+        let obsidian_robot_ore_cost = parts[18]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid obsidian_robot_ore_cost: {}", e))?;
+        let obsidian_robot_clay_cost = parts[21]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid obsidian_robot_clay_cost: {}", e))?;
+        let geode_robot_ore_cost = parts[27]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid geode_robot_ore_cost: {}", e))?;
+        let geode_robot_obsidian_cost = parts[30]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid geode_robot_obsidian_cost: {}", e))?;
+        Ok(Blueprint {
+            id,
+            ore_robot_ore_cost,
+            clay_robot_ore_cost,
+            obsidian_robot_ore_cost,
+            obsidian_robot_clay_cost,
+            geode_robot_ore_cost,
+            geode_robot_obsidian_cost,
+        })
+    }
+}
+impl Blueprint {
+    /// Return the maximum number of geodes that can be built with a given blueprint
+    fn max_geodes(&self, time: usize) -> usize {
+        let mut minimum_number_of_geodes_produced = 0;
+        let mut queue = BinaryHeap::new();
+        let strategy = Strategy {
+            blueprint: *self,
+            state: State::initial_state(time),
+        };
+        queue.push(strategy);
+
+        while let Some(next_to_expand) = queue.pop() {
+            if next_to_expand.compute_upper_bound_value() <= minimum_number_of_geodes_produced {
+                continue;
+            }
+
+            for bot_type in next_to_expand.expand() {
+                let new_state = next_to_expand
+                    .state
+                    .simulate_until_can_build_bot(self, &bot_type);
+                if new_state.geodes > minimum_number_of_geodes_produced {
+                    minimum_number_of_geodes_produced = new_state.geodes;
+                }
+                let new_strategy = Strategy {
+                    blueprint: next_to_expand.blueprint,
+                    state: new_state,
+                };
+                queue.push(new_strategy);
+            }
+        }
+
+        minimum_number_of_geodes_produced
+    }
+
+    /// Compute the quality metric for the blueprint
+    fn quality_level(&self, time: usize) -> usize {
+        let max_geodes = self.max_geodes(time);
+        self.id * max_geodes
+    }
+}
+
+pub struct Blueprints {
+    blueprints: Vec<Blueprint>,
+    part1_minutes: usize,
+    part2_minutes: usize,
+    part2_blueprints: usize,
+}
+
+pub struct Day19 {}
+impl AoCProblem for Day19 {
+    fn name(&self) -> String {
+        "day-19".to_owned()
+    }
+}
+impl Solution for Day19 {
+    type Parsed = Blueprints;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let blueprints = input.parse_lines(|l| Blueprint::try_from(l))?;
+        if blueprints.is_empty() {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected at least one blueprint",
+            ));
+        }
+        Ok(Blueprints {
+            blueprints,
+            part1_minutes: PART1_MINUTES.get(input)?,
+            part2_minutes: PART2_MINUTES.get(input)?,
+            part2_blueprints: PART2_BLUEPRINTS.get(input)?,
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![
+            PART1_MINUTES.info(),
+            PART2_MINUTES.info(),
+            PART2_BLUEPRINTS.info(),
+        ]
+    }
+
+    fn part_one(&self, blueprints: &Self::Parsed) -> AocResult<Answer> {
+        let quality_levels = blueprints
+            .blueprints
+            .iter()
+            .map(|bp| bp.quality_level(blueprints.part1_minutes))
+            .sum::<usize>();
+        Ok(quality_levels.into())
+    }
+
+    fn part_two(&self, blueprints: &Self::Parsed) -> AocResult<Answer> {
+        let p2 = blueprints
+            .blueprints
+            .iter()
+            .take(blueprints.part2_blueprints)
+            .map(|bp| bp.max_geodes(blueprints.part2_minutes))
+            .product::<usize>();
+        Ok(p2.into())
+    }
+}
@@ -0,0 +1,182 @@
+use shared::{Answer, AoCProblem, AocResult, Input, Solution};
+
+#[derive(Clone)]
+enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+impl Outcome {
+    fn score(&self) -> i32 {
+        match &self {
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Loss => 0,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone)]
+enum Play {
+    Rock,
+    Paper,
+    Scissors,
+}
+impl Play {
+    fn score(&self) -> i32 {
+        match &self {
+            Play::Rock => 1,
+            Play::Paper => 2,
+            Play::Scissors => 3,
+        }
+    }
+    fn beats(&self) -> Play {
+        match &self {
+            Play::Rock => Play::Scissors,
+            Play::Paper => Play::Rock,
+            Play::Scissors => Play::Paper,
+        }
+    }
+    fn loses_to(&self) -> Play {
+        match &self {
+            Play::Rock => Play::Paper,
+            Play::Paper => Play::Scissors,
+            Play::Scissors => Play::Rock,
+        }
+    }
+}
+
+pub struct Round {
+    player_play: Play,
+    outcome: Outcome,
+}
+impl Round {
+    fn score(&self) -> i32 {
+        self.player_play.score() + self.outcome.score()
+    }
+}
+
+impl TryFrom<char> for Play {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'A' | 'X' => Ok(Play::Rock),
+            'B' | 'Y' => Ok(Play::Paper),
+            'C' | 'Z' => Ok(Play::Scissors),
+            _ => Err("Unrecognized play".to_owned()),
+        }
+    }
+}
+
+impl TryFrom<(Outcome, Play)> for Play {
+    type Error = String;
+
+    fn try_from(value: (Outcome, Play)) -> Result<Self, Self::Error> {
+        match value {
+            (Outcome::Win, k) => Ok(k.loses_to()),
+            (Outcome::Loss, k) => Ok(k.beats()),
+            (Outcome::Draw, k) => Ok(k),
+        }
+    }
+}
+
+// (Opponent, Player)
+impl TryFrom<&(Play, Play)> for Outcome {
+    type Error = String;
+
+    fn try_from(value: &(Play, Play)) -> Result<Self, Self::Error> {
+        match value {
+            (x, y) if x == y => Ok(Outcome::Draw),
+            (x, y) if x.beats() == *y => Ok(Outcome::Loss),
+            (x, y) if x.loses_to() == *y => Ok(Outcome::Win),
+            _ => Err("Unrecognized play combination".to_owned()),
+        }
+    }
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'X' => Ok(Outcome::Loss),
+            'Y' => Ok(Outcome::Draw),
+            'Z' => Ok(Outcome::Win),
+            _ => Err("Unrecognized outcome".to_owned()),
+        }
+    }
+}
+
+/// The first character of each of the two space separated columns of a line
+fn columns(value: &str) -> Result<(char, char), String> {
+    let mut parts = value.trim().split(' ').map(|p| p.chars().next());
+    match (parts.next(), parts.next()) {
+        (Some(Some(a)), Some(Some(b))) => Ok((a, b)),
+        _ => Err(format!("expected two columns, found '{}'", value)),
+    }
+}
+
+impl TryFrom<&str> for Round {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (opponent, player) = columns(value)?;
+        let opponent_play: Play = Play::try_from(opponent)?;
+        let player_play: Play = Play::try_from(player)?;
+        let outcome: Outcome = Outcome::try_from(&(opponent_play, player_play.clone()))?;
+        Ok(Round {
+            player_play,
+            outcome,
+        })
+    }
+}
+
+pub struct Round2(Round);
+impl TryFrom<&str> for Round2 {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (opponent, outcome) = columns(value)?;
+        let opponent_play: Play = Play::try_from(opponent)?;
+        let outcome: Outcome = Outcome::try_from(outcome)?;
+        let player_play: Play = Play::try_from((outcome.clone(), opponent_play))?;
+        Ok(Round2(Round {
+            player_play,
+            outcome,
+        }))
+    }
+}
+
+pub struct Day2 {}
+impl AoCProblem for Day2 {
+    fn name(&self) -> String {
+        "day-2".to_owned()
+    }
+}
+impl Solution for Day2 {
+    // The strategy guide, read as plays for part one and as outcomes for part two
+    type Parsed = (Vec<Round>, Vec<Round2>);
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        Ok((
+            input.parse_lines(|l| Round::try_from(l))?,
+            input.parse_lines(|l| Round2::try_from(l))?,
+        ))
+    }
+
+    fn part_one(&self, (rounds, _): &Self::Parsed) -> AocResult<Answer> {
+        let scores: Vec<i32> = rounds.iter().map(|round| round.score()).collect();
+        Ok(scores.iter().sum::<i32>().into())
+    }
+
+    fn part_two(&self, (_, rounds): &Self::Parsed) -> AocResult<Answer> {
+        let scores2: Vec<i32> = rounds
+            .iter()
+            .map(|round| match round {
+                Round2(r) => r.score(),
+            })
+            .collect();
+        Ok(scores2.iter().sum::<i32>().into())
+    }
+}
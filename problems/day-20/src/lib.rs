@@ -0,0 +1,167 @@
+use cyclic_list::List;
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution};
+
+// Part two's key, and how many times it mixes the file
+const DECRYPTION_KEY: Param<i64> = Param::new("decryption_key", 811589153);
+const PART2_ROUNDS: Param<usize> = Param::new("part2_rounds", 10);
+
+/*
+    Basically the goal is to re-arrange a list while iterating through the elements.
+
+    Some observations:
+        - Indexes go both forwards and backawards
+        - Some are larger than the size of the list in general, meaning they go around
+          multiple times, which can be simplified
+        - The list is circular, and some instructions are larger than the remaining
+          portion, which is isomorphic to a transformation in the other direction
+        -
+
+    Let's start with the naive case, work on optimizing
+*/
+
+fn showv(label: String, list: &List<(usize, i64)>, debug: bool) -> &List<(usize, i64)> {
+    if !debug {
+        return list;
+    }
+    let list_len = list.len();
+    let to_print = list
+        .iter()
+        .map(|(i, v)| format!("{}:{} ({})", i, *v, v.rem_euclid((list_len - 1) as i64)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!("{}:", label);
+    println!("{}\n", to_print);
+    list
+}
+
+fn part_one(list: &mut List<(usize, i64)>) -> i64 {
+    let mut values = Vec::new();
+
+    let mut cursor = list.cursor_start_mut();
+
+    while !cursor.current().expect("Should init").1.eq(&0) {
+        cursor.move_next_cyclic();
+    }
+
+    for i in 0..3001 {
+        if i == 1000 || i == 2000 || i == 3000 {
+            values.push(cursor.current().expect("Should have value at"));
+        }
+        cursor.move_next_cyclic();
+        if cursor.current().is_none() {
+            cursor.move_next_cyclic();
+        }
+    }
+    values.iter().map(|(_, v)| v).sum::<i64>()
+}
+
+fn mix(lines: Vec<(usize, i64)>, times: usize, debug: bool) -> List<(usize, i64)> {
+    let list_size = lines.len() as i64;
+    let modulus = list_size - 1;
+
+    let mut to_mix = List::from_iter(lines.clone());
+    showv("Start".to_owned(), &to_mix, debug);
+
+    for round in 0..times {
+        for (original_index, value) in lines.iter() {
+            let modded_value = value.rem_euclid(modulus);
+
+            // No need to move  if it's a 0
+            if modded_value.eq(&0) {
+                continue;
+            }
+
+            let mut cursor = to_mix.cursor_start_mut();
+
+            // Advance the cursor until we find the corresponding item
+            let mut current = cursor.current().expect("Should exist");
+            while !original_index.eq(&current.0) {
+                cursor.move_next_cyclic();
+                if cursor.current().is_none() {
+                    cursor.move_next_cyclic();
+                }
+                current = cursor.current().expect("Should exist");
+            }
+            // Remove the item
+            let item = cursor.remove().expect("Should have item");
+            //// If removing the item puts us on the ghost node, move to next
+            if cursor.current().is_none() {
+                cursor.move_next_cyclic();
+            }
+
+            // Shift the cursor
+            for _ in 0..modded_value {
+                cursor.move_next_cyclic();
+                if cursor.current().is_none() {
+                    cursor.move_next_cyclic();
+                }
+            }
+
+            // Insert the item
+            //// If we're at the start move back to the other side of the ghost node
+            if cursor.index() == 0 {
+                cursor.move_prev_cyclic();
+            }
+            cursor.insert(item);
+
+            showv(format!("Step {}, move {}", round, value), &to_mix, debug);
+        }
+        showv(format!("Round {}:", 1 + round), &to_mix, debug);
+    }
+
+    to_mix
+}
+
+pub struct EncryptedFile {
+    numbers: Vec<(usize, i64)>,
+    decryption_key: i64,
+    part2_rounds: usize,
+}
+
+pub struct Day20 {}
+impl AoCProblem for Day20 {
+    fn name(&self) -> String {
+        "day-20".to_string()
+    }
+}
+
+impl Solution for Day20 {
+    type Parsed = EncryptedFile;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let lines = input
+            .parse_lines(|line| line.parse::<i64>())?
+            .into_iter()
+            .enumerate()
+            .collect::<Vec<_>>();
+        // The grove coordinates are counted from the 0
+        if !lines.iter().any(|(_, v)| *v == 0) {
+            return Err(AocError::invariant("The file should contain a 0"));
+        }
+        Ok(EncryptedFile {
+            numbers: lines,
+            decryption_key: DECRYPTION_KEY.get(input)?,
+            part2_rounds: PART2_ROUNDS.get(input)?,
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![DECRYPTION_KEY.info(), PART2_ROUNDS.info()]
+    }
+
+    fn part_one(&self, file: &Self::Parsed) -> AocResult<Answer> {
+        let mut to_mix = mix(file.numbers.clone(), 1, false);
+        Ok(part_one(&mut to_mix).into())
+    }
+
+    fn part_two(&self, file: &Self::Parsed) -> AocResult<Answer> {
+        let lines = file
+            .numbers
+            .iter()
+            .map(|(i, v)| (*i, v * file.decryption_key))
+            .collect::<Vec<_>>();
+
+        let mut to_mix = mix(lines, file.part2_rounds, false);
+        Ok(part_one(&mut to_mix).into())
+    }
+}
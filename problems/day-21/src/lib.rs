@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+#[derive(Debug, Clone)]
+enum Expression {
+    Value(i64),
+    Sym(String),
+    Add(Box<Expression>, Box<Expression>),
+    Sub(Box<Expression>, Box<Expression>),
+    Mul(Box<Expression>, Box<Expression>),
+    Div(Box<Expression>, Box<Expression>),
+    Eq(Box<Expression>, Box<Expression>),
+}
+impl Expression {
+    fn evaluate(&self) -> Result<i64, String> {
+        match self {
+            Expression::Value(i) => Ok(*i),
+            Expression::Add(lhs, rhs) => Ok((*lhs).evaluate()? + (*rhs).evaluate()?),
+            Expression::Sub(lhs, rhs) => Ok((*lhs).evaluate()? - (*rhs).evaluate()?),
+            Expression::Mul(lhs, rhs) => Ok((*lhs).evaluate()? * (*rhs).evaluate()?),
+            Expression::Div(lhs, rhs) => Ok((*lhs).evaluate()? / (*rhs).evaluate()?),
+            Expression::Eq(lhs, rhs) => {
+                Expression::Sub(Box::new(*lhs.clone()), Box::new(*rhs.clone())).solve()
+            }
+            Expression::Sym(s) => Err(format!("Cannot evaluate unknown symbol: {}", s)),
+        }
+    }
+
+    fn invert(&self, result: &Expression) -> Result<(Expression, Expression), String> {
+        // Split up each side, try to evaluate each side
+        // If evaluation fails with "Cannot evalute unknown symbol: humn" then we need to invert the op and apply it, returning the remaining expression to recurse
+        match self {
+            Expression::Add(lhs, rhs) => {
+                let (to_apply, to_solve) = match lhs.evaluate() {
+                    Ok(r) => (r, rhs),
+                    Err(_) => (
+                        rhs.evaluate().map_err(|e| {
+                            format!("Unable to evaluate either side of Add expression: {}", e)
+                        })?,
+                        lhs,
+                    ),
+                };
+                Ok((
+                    *to_solve.clone(),
+                    Expression::Sub(
+                        Box::new(result.clone()),
+                        Box::new(Expression::Value(to_apply)),
+                    ),
+                ))
+            }
+            Expression::Sub(lhs, rhs) => match lhs.evaluate() {
+                Ok(r) => Ok((
+                    *rhs.clone(),
+                    Expression::Sub(Box::new(Expression::Value(r)), Box::new(result.clone())),
+                )),
+                Err(_) => Ok((
+                    *lhs.clone(),
+                    Expression::Add(
+                        Box::new(result.clone()),
+                        Box::new(Expression::Value(rhs.evaluate().map_err(|e| {
+                            format!("Unable to evaluate either side of Sub expression: {}", e)
+                        })?)),
+                    ),
+                )),
+            },
+            Expression::Mul(lhs, rhs) => {
+                let (to_apply, to_solve) = match lhs.evaluate() {
+                    Ok(r) => (r, rhs),
+                    Err(_) => (
+                        rhs.evaluate().map_err(|e| {
+                            format!("Unable to evaluate either side of Mul expression: {}", e)
+                        })?,
+                        lhs,
+                    ),
+                };
+                Ok((
+                    *to_solve.clone(),
+                    Expression::Div(
+                        Box::new(result.clone()),
+                        Box::new(Expression::Value(to_apply)),
+                    ),
+                ))
+            }
+            Expression::Div(lhs, rhs) => match lhs.evaluate() {
+                Ok(r) => Ok((
+                    *rhs.clone(),
+                    Expression::Div(Box::new(Expression::Value(r)), Box::new(result.clone())),
+                )),
+                Err(_) => Ok((
+                    *lhs.clone(),
+                    Expression::Mul(
+                        Box::new(result.clone()),
+                        Box::new(Expression::Value(rhs.evaluate().map_err(|e| {
+                            format!("Unable to evaluate either side of Div expression: {}", e)
+                        })?)),
+                    ),
+                )),
+            },
+            _ => Err("Cannot invert non-binary operation".to_owned()),
+        }
+    }
+
+    fn solve_rec(remainder: &Expression, result: &Expression) -> Result<i64, String> {
+        match remainder {
+            Expression::Sym(_) => result.evaluate(),
+            Expression::Value(_) => Err("No symbol found".to_owned()),
+            Expression::Eq(_, _) => Err("Multiple equivalnces found".to_owned()),
+            _op => {
+                let (remainder, result) = remainder.invert(result)?;
+                Self::solve_rec(&remainder, &result)
+            }
+        }
+    }
+
+    fn solve(&self) -> Result<i64, String> {
+        let (remainder, result) = self.invert(&Expression::Value(0))?;
+        Self::solve_rec(&remainder, &result)
+    }
+
+    fn from(lines: &HashMap<String, String>, key: String) -> Result<Expression, String> {
+        let Some(subexpression) = lines.get(&key) else {
+            return Ok(Expression::Sym(key));
+        };
+
+        // Try to parse literal value
+        let literal_result = subexpression.parse::<i64>();
+        if let Ok(literal_value) = literal_result {
+            return Ok(Expression::Value(literal_value));
+        }
+
+        // Otherwise it must be an operation
+        let (lhs, op, rhs) = subexpression
+            .split(' ')
+            .collect_tuple()
+            .ok_or(format!("Unexpected number of parts in {}", subexpression))?;
+
+        let left_subexpression = Expression::from(lines, lhs.to_owned())
+            .map_err(|e| format!("Failed to parse lhs: {}", e))?;
+        let right_subexpression = Expression::from(lines, rhs.to_owned())
+            .map_err(|e| format!("Failed to parses rhs: {}", e))?;
+
+        match op {
+            "+" => Ok(Expression::Add(
+                Box::new(left_subexpression),
+                Box::new(right_subexpression),
+            )),
+            "-" => Ok(Expression::Sub(
+                Box::new(left_subexpression),
+                Box::new(right_subexpression),
+            )),
+            "*" => Ok(Expression::Mul(
+                Box::new(left_subexpression),
+                Box::new(right_subexpression),
+            )),
+            "/" => Ok(Expression::Div(
+                Box::new(left_subexpression),
+                Box::new(right_subexpression),
+            )),
+            "=" => Ok(Expression::Eq(
+                Box::new(left_subexpression),
+                Box::new(right_subexpression),
+            )),
+            _ => Err(format!("Unrecognized op: {}", op)),
+        }
+    }
+}
+impl TryFrom<HashMap<String, String>> for Expression {
+    type Error = String;
+
+    fn try_from(value: HashMap<String, String>) -> Result<Self, Self::Error> {
+        Expression::from(&value, "root".to_owned())
+    }
+}
+
+pub struct Day21 {}
+impl AoCProblem for Day21 {
+    fn name(&self) -> String {
+        "day-21".to_owned()
+    }
+}
+impl Solution for Day21 {
+    type Parsed = Vec<(String, String)>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let monkeys = input.parse_lines(|line| {
+            line.split(": ")
+                .collect_tuple::<(&str, &str)>()
+                .map(|(key, subexpression)| (key.to_owned(), subexpression.to_owned()))
+                .ok_or("expected '<name>: <job>'")
+        })?;
+        if !monkeys.iter().any(|(key, _)| key == "root") {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected a 'root' monkey",
+            ));
+        }
+        Ok(monkeys)
+    }
+
+    fn part_one(&self, monkeys: &Self::Parsed) -> AocResult<Answer> {
+        let line_map = monkeys.iter().cloned().collect::<HashMap<_, _>>();
+        let expression = Expression::try_from(line_map).map_err(AocError::invariant)?;
+        Ok(expression.evaluate().map_err(AocError::invariant)?.into())
+    }
+
+    fn part_two(&self, monkeys: &Self::Parsed) -> AocResult<Answer> {
+        let mut line_map = monkeys
+            .iter()
+            .filter(|(key, _)| key != "humn")
+            .cloned()
+            .collect::<HashMap<_, _>>();
+
+        let updated_root = line_map
+            .get("root")
+            .ok_or_else(|| AocError::invariant("There should be a 'root' monkey"))?
+            .replace(['+', '-', '*', '/'], "=");
+        line_map.insert("root".to_owned(), updated_root);
+        let expression = Expression::try_from(line_map).map_err(AocError::invariant)?;
+        Ok(expression.evaluate().map_err(AocError::invariant)?.into())
+    }
+}
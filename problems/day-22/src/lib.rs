@@ -0,0 +1,695 @@
+use std::collections::{HashMap, HashSet};
+
+use shared::{geom::Point3, Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+#[derive(Debug)]
+enum Rotation {
+    L,
+    R,
+    // Identity - don't rotate
+    I,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Orientation {
+    L,
+    R,
+    U,
+    D,
+}
+impl Orientation {
+    fn facing(&self) -> i32 {
+        match self {
+            Orientation::L => 2,
+            Orientation::R => 0,
+            Orientation::U => 3,
+            Orientation::D => 1,
+        }
+    }
+
+    fn rotate(&self, rotation: &Rotation) -> Orientation {
+        match (self, rotation) {
+            (Orientation::L, Rotation::L) => Orientation::D,
+            (Orientation::L, Rotation::R) => Orientation::U,
+            (Orientation::R, Rotation::L) => Orientation::U,
+            (Orientation::R, Rotation::R) => Orientation::D,
+            (Orientation::U, Rotation::L) => Orientation::L,
+            (Orientation::U, Rotation::R) => Orientation::R,
+            (Orientation::D, Rotation::L) => Orientation::R,
+            (Orientation::D, Rotation::R) => Orientation::L,
+            (_, Rotation::I) => *self,
+        }
+    }
+
+    // How the puzzle draws a path heading this way
+    fn arrow(&self) -> char {
+        match self {
+            Orientation::L => '<',
+            Orientation::R => '>',
+            Orientation::U => '^',
+            Orientation::D => 'v',
+        }
+    }
+
+    // (row, col) offset of a single step
+    fn step(&self) -> (i32, i32) {
+        match self {
+            Orientation::L => (0, -1),
+            Orientation::R => (0, 1),
+            Orientation::U => (-1, 0),
+            Orientation::D => (1, 0),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    magnitude: i32,
+    rotation: Rotation,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct Coord {
+    row: i32,
+    col: i32,
+}
+
+#[derive(Clone, Debug)]
+struct State {
+    coordinate: Coord,
+    orientation: Orientation,
+}
+impl State {
+    fn new(board: &Board) -> Option<State> {
+        let mut start_col = 0;
+        while start_col < board.width {
+            let coord = Coord {
+                row: 0,
+                col: start_col,
+            };
+            if board.get_tile(&coord) == Some('.') {
+                return Some(State {
+                    coordinate: coord,
+                    orientation: Orientation::R,
+                });
+            }
+            start_col += 1;
+        }
+        None
+    }
+}
+
+// Each neighbor is the tile a step leads to, and the way you face once you're there
+#[derive(Debug)]
+struct Node {
+    tile: char,
+    neighbor_up: Option<(Coord, Orientation)>,
+    neighbor_down: Option<(Coord, Orientation)>,
+    neighbor_left: Option<(Coord, Orientation)>,
+    neighbor_right: Option<(Coord, Orientation)>,
+}
+impl Node {
+    fn new_empty(tile: char) -> Self {
+        Node {
+            tile,
+            neighbor_up: None,
+            neighbor_down: None,
+            neighbor_left: None,
+            neighbor_right: None,
+        }
+    }
+
+    fn neighbor_mut(&mut self, orientation: &Orientation) -> &mut Option<(Coord, Orientation)> {
+        match orientation {
+            Orientation::L => &mut self.neighbor_left,
+            Orientation::R => &mut self.neighbor_right,
+            Orientation::U => &mut self.neighbor_up,
+            Orientation::D => &mut self.neighbor_down,
+        }
+    }
+}
+
+// Directions across a cube face as it sits in space, once the net is folded up
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    // Points out of the cube
+    normal: Point3<i32>,
+    // Points along increasing columns
+    right: Point3<i32>,
+    // Points along increasing rows
+    down: Point3<i32>,
+}
+impl Frame {
+    fn along(&self, orientation: &Orientation) -> Point3<i32> {
+        match orientation {
+            Orientation::L => -self.right,
+            Orientation::R => self.right,
+            Orientation::U => -self.down,
+            Orientation::D => self.down,
+        }
+    }
+
+    // The frame of the face next to this one in the net, after folding along their shared edge
+    fn fold(&self, towards: &Orientation) -> Frame {
+        let normal = self.along(towards);
+        match towards {
+            Orientation::L | Orientation::R => Frame {
+                normal,
+                right: if *towards == Orientation::R {
+                    -self.normal
+                } else {
+                    self.normal
+                },
+                down: self.down,
+            },
+            Orientation::U | Orientation::D => Frame {
+                normal,
+                right: self.right,
+                down: if *towards == Orientation::D {
+                    -self.normal
+                } else {
+                    self.normal
+                },
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Board {
+    map: HashMap<Coord, Node>,
+    width: i32,
+    height: i32,
+}
+impl Board {
+    fn get_tile(&self, coord: &Coord) -> Option<char> {
+        self.map.get(coord).map(|n| n.tile)
+    }
+    fn try_move_one(
+        &self,
+        start: &State,
+        current_position: Coord,
+        orientation: &Orientation,
+    ) -> State {
+        let current_node = self
+            .map
+            .get(&current_position)
+            .expect("Current node should be in board");
+        let (neighbor, next_orientation) = match orientation {
+            Orientation::L => current_node.neighbor_left.clone(),
+            Orientation::R => current_node.neighbor_right.clone(),
+            Orientation::U => current_node.neighbor_up.clone(),
+            Orientation::D => current_node.neighbor_down.clone(),
+        }
+        .expect("Neighbor should always exist");
+
+        let neighbor_tile = self
+            .get_tile(&neighbor)
+            .expect("Neighbor tile should exist");
+
+        match neighbor_tile {
+            ' ' => self.try_move_one(start, neighbor, &next_orientation),
+            '.' => State {
+                coordinate: neighbor,
+                orientation: next_orientation,
+            },
+            '#' => start.clone(),
+            _ => unreachable!("Boards should only have ' ', '.' and '#' tiles"),
+        }
+    }
+
+    // The same tiles folded up into a cube, so that walking off the edge of a face carries on
+    // over the neighboring face. Works for any of the nets a cube can be cut into.
+    fn fold(&self) -> Result<Board, String> {
+        let tiles = self
+            .map
+            .iter()
+            .filter(|(_, node)| node.tile != ' ')
+            .map(|(coord, _)| coord.to_owned())
+            .collect::<Vec<Coord>>();
+        let size = (1..)
+            .take_while(|size| 6 * size * size <= tiles.len())
+            .last()
+            .unwrap_or(0);
+        if size == 0 || 6 * size * size != tiles.len() {
+            return Err(format!(
+                "expected the board to be six square faces, found {} tiles",
+                tiles.len()
+            ));
+        }
+        let size = size as i32;
+        let face_of = |coord: &Coord| (coord.row / size, coord.col / size);
+        let faces = tiles.iter().map(face_of).collect::<HashSet<_>>();
+        if faces.len() != 6 {
+            return Err(format!("expected six faces, found {}", faces.len()));
+        }
+
+        // Orient every face by walking the net from the first face
+        let first = *faces.iter().min().expect("Should have six faces");
+        let mut frames = HashMap::new();
+        frames.insert(
+            first,
+            Frame {
+                normal: Point3::new(0, 0, -1),
+                right: Point3::new(1, 0, 0),
+                down: Point3::new(0, 1, 0),
+            },
+        );
+        let mut to_visit = vec![first];
+        while let Some(face) = to_visit.pop() {
+            let frame = frames[&face];
+            for orientation in [
+                Orientation::L,
+                Orientation::R,
+                Orientation::U,
+                Orientation::D,
+            ] {
+                let (d_row, d_col) = orientation.step();
+                let next = (face.0 + d_row, face.1 + d_col);
+                if faces.contains(&next) && !frames.contains_key(&next) {
+                    frames.insert(next, frame.fold(&orientation));
+                    to_visit.push(next);
+                }
+            }
+        }
+        let normals = frames.values().map(|f| f.normal).collect::<HashSet<_>>();
+        if normals.len() != 6 {
+            return Err("expected the faces to fold into a cube".to_owned());
+        }
+
+        // Where each tile's center ends up, in units of half a tile from the cube's center
+        let center = |coord: &Coord| {
+            let frame = frames[&face_of(coord)];
+            let (row, col) = (coord.row % size, coord.col % size);
+            frame.normal * size
+                + frame.right * (2 * col + 1 - size)
+                + frame.down * (2 * row + 1 - size)
+        };
+        let by_center = tiles
+            .iter()
+            .map(|coord| (center(coord), coord.to_owned()))
+            .collect::<HashMap<_, _>>();
+
+        let mut node_map = HashMap::new();
+        for coord in tiles.iter() {
+            let mut node = Node::new_empty(self.get_tile(coord).expect("Tile should exist"));
+            let frame = frames[&face_of(coord)];
+            for orientation in [
+                Orientation::L,
+                Orientation::R,
+                Orientation::U,
+                Orientation::D,
+            ] {
+                let (d_row, d_col) = orientation.step();
+                let flat = Coord {
+                    row: coord.row + d_row,
+                    col: coord.col + d_col,
+                };
+                let neighbor = if face_of(&flat) == face_of(coord) && flat.row >= 0 && flat.col >= 0
+                {
+                    (flat, orientation)
+                } else {
+                    // Over the edge, half a tile onto the next face and half a tile down it
+                    let across = center(coord) + frame.along(&orientation) - frame.normal;
+                    let target = by_center
+                        .get(&across)
+                        .expect("Should fold onto a tile")
+                        .to_owned();
+                    // Carry on heading away from the face we left
+                    let target_frame = frames[&face_of(&target)];
+                    let heading = [
+                        Orientation::L,
+                        Orientation::R,
+                        Orientation::U,
+                        Orientation::D,
+                    ]
+                    .into_iter()
+                    .find(|o| target_frame.along(o) == -frame.normal)
+                    .expect("Should head away from the seam");
+                    (target, heading)
+                };
+                *node.neighbor_mut(&orientation) = Some(neighbor);
+            }
+            node_map.insert(coord.to_owned(), node);
+        }
+
+        Ok(Board {
+            map: node_map,
+            width: self.width,
+            height: self.height,
+        })
+    }
+}
+impl From<&[&str]> for Board {
+    fn from(lines: &[&str]) -> Self {
+        let mut node_map = HashMap::new();
+        let mut key_set = HashSet::new();
+
+        // Rows can stop short of the board's right edge; pad them out with blank tiles
+        let height = lines.len() as i32;
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as i32;
+
+        for (row, line) in lines.iter().enumerate() {
+            let tiles = line.chars().collect::<Vec<_>>();
+            for col in 0..width as usize {
+                let coord = Coord {
+                    row: row as i32,
+                    col: col as i32,
+                };
+                let tile = tiles.get(col).copied().unwrap_or(' ');
+                let node = Node::new_empty(tile);
+                key_set.insert(coord.clone());
+                node_map.insert(coord, node);
+            }
+        }
+
+        // Connect grid to neighbors
+        for (coord, node) in node_map.iter_mut() {
+            for orientation in [
+                Orientation::L,
+                Orientation::R,
+                Orientation::U,
+                Orientation::D,
+            ] {
+                let (d_row, d_col) = orientation.step();
+                let neighbor = Coord {
+                    row: coord.row + d_row,
+                    col: coord.col + d_col,
+                };
+                *node.neighbor_mut(&orientation) =
+                    key_set.get(&neighbor).map(|c| (c.to_owned(), orientation));
+            }
+        }
+
+        // Connect edges - Left to right
+        for row in 0..height {
+            let head_coord = Coord { row, col: 0 };
+            let tail_coord = Coord {
+                row,
+                col: width - 1,
+            };
+
+            let head_node = node_map
+                .get_mut(&head_coord)
+                .expect("Head node should exist");
+            head_node.neighbor_left = Some((tail_coord.clone(), Orientation::L));
+
+            let tail_node = node_map
+                .get_mut(&tail_coord)
+                .expect("Tail node should exist");
+            tail_node.neighbor_right = Some((head_coord.clone(), Orientation::R));
+        }
+
+        // Connect edges - Up to down
+        for col in 0..width {
+            let head_coord = Coord { row: 0, col };
+            let tail_coord = Coord {
+                row: height - 1,
+                col,
+            };
+
+            let head_node = node_map
+                .get_mut(&head_coord)
+                .expect("Head node should exist");
+            head_node.neighbor_up = Some((tail_coord.clone(), Orientation::U));
+
+            let tail_node = node_map
+                .get_mut(&tail_coord)
+                .expect("Tail node should exist");
+            tail_node.neighbor_down = Some((head_coord.clone(), Orientation::D));
+        }
+
+        Board {
+            map: node_map,
+            width,
+            height,
+        }
+    }
+}
+
+// Every state along the way, from the start to the final position and orientation
+fn walk(board: &Board, instructions: &Vec<Instruction>) -> Option<Vec<State>> {
+    // Find the startig point
+    let mut current_state = State::new(board)?;
+    let mut trail = vec![current_state.clone()];
+
+    // For each instruction, try and follow
+    for instruction in instructions {
+        current_state = follow_instruction(board, &current_state, instruction, &mut trail);
+    }
+
+    Some(trail)
+}
+
+// The board with the trail drawn over it, each tile showing the way it was last left facing
+fn render_trail(board: &Board, trail: &[State]) -> String {
+    let facings = trail
+        .iter()
+        .map(|state| (state.coordinate.clone(), state.orientation))
+        .collect::<HashMap<Coord, Orientation>>();
+    (0..board.height)
+        .map(|row| {
+            (0..board.width)
+                .map(|col| {
+                    let coord = Coord { row, col };
+                    match facings.get(&coord) {
+                        Some(orientation) => orientation.arrow(),
+                        None => board.get_tile(&coord).unwrap_or(' '),
+                    }
+                })
+                .collect::<String>()
+                .trim_end()
+                .to_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Follow one instruction, recording each state along the way in `trail`
+fn follow_instruction(
+    board: &Board,
+    state: &State,
+    instruction: &Instruction,
+    trail: &mut Vec<State>,
+) -> State {
+    let mut current_state = state.clone();
+
+    for _ in 0..instruction.magnitude {
+        let new_state = board.try_move_one(
+            &current_state,
+            current_state.coordinate.clone(),
+            &current_state.orientation,
+        );
+        if current_state.coordinate == new_state.coordinate {
+            break;
+        }
+        current_state = new_state;
+        trail.push(current_state.clone());
+    }
+
+    // Update orientation
+    let turned = State {
+        orientation: current_state.orientation.rotate(&instruction.rotation),
+        ..current_state
+    };
+    trail.push(turned.clone());
+    turned
+}
+
+fn password(state: &State) -> i32 {
+    (1000 * (state.coordinate.row + 1))
+        + (4 * (state.coordinate.col + 1))
+        + state.orientation.facing()
+}
+
+fn trail(board: &Board, instructions: &Vec<Instruction>) -> AocResult<Vec<State>> {
+    walk(board, instructions)
+        .ok_or_else(|| AocError::invariant("The board should have an open tile to start from"))
+}
+
+pub struct Day22 {}
+impl Day22 {
+    /// Both parts' paths drawn over the board, the way the puzzle draws them
+    pub fn trace(&self, input: &Input) -> AocResult<String> {
+        let (board, instructions) = self.parse(input)?;
+        let flat = render_trail(&board, &trail(&board, &instructions)?);
+        let cube = board.fold().map_err(AocError::invariant)?;
+        let folded = render_trail(&board, &trail(&cube, &instructions)?);
+        Ok(format!("Part one:\n{}\n\nPart two:\n{}\n", flat, folded))
+    }
+}
+impl AoCProblem for Day22 {
+    fn name(&self) -> String {
+        "day-22".to_owned()
+    }
+}
+impl Solution for Day22 {
+    type Parsed = (Board, Vec<Instruction>);
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let lines = input.lines().collect::<Vec<_>>();
+        if lines.len() < 3 {
+            return Err(AocError::parse_file(
+                input.path(),
+                "expected a board, a blank line, and a path",
+            ));
+        }
+        let instruction_error =
+            |message: String| AocError::parse(input.path(), lines.len(), message);
+
+        let last_line = format!(
+            // Tack on an identity rotation at the end, just for symmetry
+            "{}:I",
+            lines[lines.len() - 1]
+        );
+        let instructions = last_line
+            .replace('L', ":L:")
+            .replace('R', ":R:")
+            .split(':')
+            .collect::<Vec<_>>()
+            // Frustratingly, .array_chunks(..) isn't stable yet, so we window and skip
+            // every odd numbered entry
+            .windows(2)
+            .enumerate()
+            .filter(|(i, _)| i % 2 == 0)
+            .map(|(_, v)| {
+                let magnitude = v[0].parse::<i32>().map_err(|e| {
+                    instruction_error(format!("invalid distance '{}': {}", v[0], e))
+                })?;
+                let rotation = match v[1] {
+                    "L" => Rotation::L,
+                    "R" => Rotation::R,
+                    "I" => Rotation::I,
+                    _ => {
+                        return Err(instruction_error(format!(
+                            "unrecognized rotation '{}'",
+                            v[1]
+                        )))
+                    }
+                };
+                Ok(Instruction {
+                    magnitude,
+                    rotation,
+                })
+            })
+            .collect::<AocResult<Vec<_>>>()?;
+
+        // Everything above the blank line and the path
+        let board_lines = &lines[..lines.len() - 2];
+        for (row, line) in board_lines.iter().enumerate() {
+            if let Some((col, tile)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, tile)| !matches!(tile, ' ' | '.' | '#'))
+            {
+                return Err(AocError::parse(
+                    input.path(),
+                    row + 1,
+                    format!("expected ' ', '.' or '#' on the board, found '{}'", tile),
+                )
+                .at_column(col + 1));
+            }
+        }
+        let board = Board::from(board_lines);
+        Ok((board, instructions))
+    }
+
+    fn part_one(&self, (board, instructions): &Self::Parsed) -> AocResult<Answer> {
+        let trail = trail(board, instructions)?;
+        Ok(password(trail.last().expect("Should start somewhere")).into())
+    }
+
+    fn part_two(&self, (board, instructions): &Self::Parsed) -> AocResult<Answer> {
+        let trail = trail(&board.fold().map_err(AocError::invariant)?, instructions)?;
+        Ok(password(trail.last().expect("Should start somewhere")).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn trace_should_draw_path() {
+        let example = [
+            "        ...#",
+            "        .#..",
+            "        #...",
+            "        ....",
+            "...#.......#",
+            "........#...",
+            "..#....#....",
+            "..........#.",
+            "        ...#....",
+            "        .....#..",
+            "        .#......",
+            "        ......#.",
+            "",
+            "10R5L5R10L4R5L5",
+        ]
+        .join("\n");
+        let trace = Day22 {}
+            .trace(&Input::from_text("example", &example))
+            .expect("Should trace");
+        let part_two = trace
+            .split("Part two:\n")
+            .nth(1)
+            .expect("Should trace part two");
+        assert_eq!(
+            part_two.lines().skip(4).take(4).collect::<Vec<_>>(),
+            vec![
+                "...#..^...v#",
+                ".>>>>>^.#.>>",
+                ".^#....#....",
+                ".^........#."
+            ]
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_tiles() {
+        let input = Input::from_text("example", ".x..\n....\n\n5\n");
+        assert_eq!(
+            Day22 {}.parse(&input).err().map(|e| e.to_string()),
+            Some("example:1:2: expected ' ', '.' or '#' on the board, found 'x'".to_owned())
+        );
+    }
+
+    #[test]
+    fn fold_should_wrap_around_any_net() {
+        // A cross-shaped net, unlike the example's; with no walls, walking four faces in a
+        // straight line leads back to the start, facing the same way
+        let lines = [
+            "  ..", "  ..", "......", "......", "  ..", "  ..", "  ..", "  ..",
+        ];
+        let cube = Board::from(&lines[..]).fold().expect("Should fold");
+        let start = State::new(&cube).expect("Should have a start");
+        for rotation in [Rotation::I, Rotation::R, Rotation::L] {
+            let state = follow_instruction(
+                &cube,
+                &start,
+                &Instruction {
+                    magnitude: 0,
+                    rotation,
+                },
+                &mut vec![],
+            );
+            let end = follow_instruction(
+                &cube,
+                &state,
+                &Instruction {
+                    magnitude: 8,
+                    rotation: Rotation::I,
+                },
+                &mut vec![],
+            );
+            assert_eq!(end.coordinate, state.coordinate);
+            assert_eq!(end.orientation, state.orientation);
+        }
+        assert!(Board::from(&lines[..7]).fold().is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+fn priority(c: char) -> u32 {
+    // Annoyingly the problem puts 'A' after 'a' in the code points, so we have to do some arithmetic to swap them back
+    // a-z is [1, 27)
+    let basis = if c.is_ascii_lowercase() {
+        ('a' as u32) - 1
+    } else {
+        // A-Z is [27, 57)
+        ('A' as u32) - 1 - 26
+    };
+    (c as u32) - basis
+}
+
+fn find_overlap<'a, I>(vals: I) -> Option<char>
+where
+    I: Iterator<Item = &'a String>,
+{
+    vals.map(|s| s.chars().collect::<HashSet<char>>())
+        .reduce(|p, n| p.intersection(&n).copied().collect::<HashSet<char>>())?
+        .iter()
+        .take(1)
+        .next()
+        .map(|c| c.to_owned())
+}
+
+fn group_compartments<'a, I>(vals: I) -> Vec<Vec<String>>
+where
+    I: Iterator<Item = &'a String>,
+{
+    vals.map(|line| {
+        let parts = line.split_at(line.len() / 2);
+        vec![parts.0.to_owned(), parts.1.to_owned()]
+    })
+    .collect()
+}
+
+fn group_badges<'a, I>(vals: I) -> Vec<Vec<String>>
+where
+    I: Iterator<Item = &'a String>,
+{
+    vals.map(|s| s.to_owned())
+        .collect::<Vec<String>>()
+        .chunks(3)
+        .map(Vec::from)
+        .collect::<Vec<Vec<String>>>()
+}
+
+pub struct Day3 {}
+impl AoCProblem for Day3 {
+    fn name(&self) -> String {
+        "day-3".to_owned()
+    }
+}
+impl Solution for Day3 {
+    // One rucksack per line
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        Ok(input.lines().map(|line| line.to_owned()).collect())
+    }
+
+    fn part_one(&self, lines: &Self::Parsed) -> AocResult<Answer> {
+        let compartments = group_compartments(lines.iter());
+        let shared_item_prioities = compartments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                find_overlap(c.iter()).ok_or_else(|| {
+                    AocError::invariant(format!(
+                        "Rucksack {} has no item in both compartments",
+                        i + 1
+                    ))
+                })
+            })
+            .map(|c| c.map(priority))
+            .collect::<AocResult<Vec<u32>>>()?;
+        Ok(shared_item_prioities.iter().sum::<u32>().into())
+    }
+
+    fn part_two(&self, lines: &Self::Parsed) -> AocResult<Answer> {
+        let badge_groups = group_badges(lines.iter());
+        let badge_priorities = badge_groups
+            .iter()
+            .enumerate()
+            .map(|(i, g)| {
+                find_overlap(g.iter()).ok_or_else(|| {
+                    AocError::invariant(format!("Group {} has no badge in common", i + 1))
+                })
+            })
+            .map(|c| c.map(priority))
+            .collect::<AocResult<Vec<u32>>>()?;
+        Ok(badge_priorities.iter().sum::<u32>().into())
+    }
+}
@@ -0,0 +1,53 @@
+use shared::{interval::Interval, Answer, AoCProblem, AocResult, Input, Solution};
+
+// A range of sections like "2-4", in either order
+fn sections(value: &str) -> Result<Interval<u32>, String> {
+    let parts: Vec<u32> = value
+        .split('-')
+        .map(|part| {
+            part.parse::<u32>()
+                .map_err(|e| format!("invalid section '{}': {}", part, e))
+        })
+        .take(2)
+        .collect::<Result<_, _>>()?;
+    if parts.len() != 2 {
+        return Err("Unexpected number of parts when parsing".to_owned());
+    }
+    Ok(Interval::closed(
+        parts[0].min(parts[1]),
+        parts[0].max(parts[1]),
+    ))
+}
+
+pub struct Day4 {}
+impl AoCProblem for Day4 {
+    fn name(&self) -> String {
+        "day-4".to_owned()
+    }
+}
+impl Solution for Day4 {
+    // The pair of section assignments on each line
+    type Parsed = Vec<(Interval<u32>, Interval<u32>)>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let intervals: Vec<(Interval<u32>, Interval<u32>)> = input.parse_lines(|line| {
+            let sub_parts = line
+                .split_once(',')
+                .ok_or("Line should contain one comma")?;
+            Ok::<_, String>((sections(sub_parts.0)?, sections(sub_parts.1)?))
+        })?;
+        Ok(intervals)
+    }
+
+    fn part_one(&self, intervals: &Self::Parsed) -> AocResult<Answer> {
+        let part_one = intervals
+            .iter()
+            .filter(|pair| pair.0.covers(&pair.1) || pair.1.covers(&pair.0));
+        Ok(part_one.count().into())
+    }
+
+    fn part_two(&self, intervals: &Self::Parsed) -> AocResult<Answer> {
+        let part_two = intervals.iter().filter(|pair| pair.0.overlaps(&pair.1));
+        Ok(part_two.count().into())
+    }
+}
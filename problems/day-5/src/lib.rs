@@ -0,0 +1,183 @@
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+#[derive(Debug)]
+struct Operation {
+    count: u32,
+    from: u32,
+    to: u32,
+}
+impl TryFrom<&str> for Operation {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parts: Vec<&str> = value.split(' ').collect();
+        if parts.len() != 6 {
+            return Err("expected 'move <count> from <stack> to <stack>'".to_owned());
+        }
+        let number = |name: &str, part: &str| {
+            part.parse::<u32>()
+                .map_err(|e| format!("invalid `{}` '{}': {}", name, part, e))
+        };
+        let count = number("count", parts[1])?;
+        let from = number("from", parts[3])?;
+        let to = number("to", parts[5])?;
+        Ok(Operation { count, from, to })
+    }
+}
+
+struct StackLayer(Vec<Option<char>>);
+impl TryFrom<&str> for StackLayer {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parts: Vec<Option<char>> = value
+            .chars()
+            .enumerate()
+            .filter(|e| e.0 % 4 == 1)
+            .map(|e| {
+                if e.1.is_ascii_uppercase() {
+                    Some(e.1)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if parts.is_empty() {
+            return Err("Failed to parse stack layer".to_owned());
+        }
+        Ok(StackLayer(parts))
+    }
+}
+
+enum CraneModel {
+    Model9000,
+    Model9001,
+}
+
+pub struct State {
+    stacks: Vec<String>,
+    operations: Vec<Operation>,
+}
+impl State {
+    fn apply_moves(&self, model: CraneModel) -> State {
+        let mut stacks = self.stacks.clone();
+        for op in &self.operations {
+            let count = op.count as usize;
+            let from_idx = op.from as usize - 1;
+            let to_idx = op.to as usize - 1;
+            let from_stack = stacks[from_idx].clone();
+            let to_move = match &model {
+                CraneModel::Model9000 => from_stack.chars().rev().take(count).collect::<String>(),
+                CraneModel::Model9001 => from_stack
+                    .chars()
+                    .rev()
+                    .take(count)
+                    // Okay, this is actually annoying.
+                    // You can't chain .rev().take(...).rev(), you have to explicitly materialize in between.
+                    .collect::<String>()
+                    .chars()
+                    .rev()
+                    .collect::<String>(),
+            };
+            stacks[to_idx].push_str(to_move.as_str());
+            stacks[from_idx].truncate(from_stack.len() - count);
+        }
+        State {
+            stacks,
+            operations: vec![],
+        }
+    }
+
+    // Top of the stacks
+    fn tots(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| {
+                stack
+                    .chars()
+                    .last()
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| "".to_owned())
+            })
+            .collect::<String>()
+    }
+}
+impl TryFrom<(Vec<StackLayer>, Vec<Operation>)> for State {
+    type Error = String;
+
+    fn try_from(value: (Vec<StackLayer>, Vec<Operation>)) -> Result<Self, Self::Error> {
+        // Essentially a transpose, which I'm sad Rust doesn't provide out of the box.
+        // Make our empty stacks
+        let mut stacks = value
+            .0
+            .first()
+            .ok_or("expected at least one stack layer")?
+            .0
+            .iter()
+            .map(|_| vec![])
+            .collect::<Vec<Vec<char>>>();
+        // Populate them top-down with non-None chars
+        for layer in &value.0 {
+            for (idx, v) in layer.0.iter().enumerate() {
+                if let Some(v) = v {
+                    stacks
+                        .get_mut(idx)
+                        .ok_or("stack layer is wider than the bottom layer")?
+                        .push(*v)
+                }
+            }
+        }
+        // Reverse, so the tops are at the ends
+        let reversed = stacks
+            .iter()
+            .map(|s| s.iter().rev().copied().collect::<String>())
+            .collect::<Vec<String>>();
+        Ok(State {
+            stacks: reversed,
+            operations: value.1,
+        })
+    }
+}
+
+pub struct Day5 {}
+impl AoCProblem for Day5 {
+    fn name(&self) -> String {
+        "day-5".to_owned()
+    }
+}
+impl Solution for Day5 {
+    type Parsed = State;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        // Trying to be somewhat efficient in parsing here, for no particular reason other than I want to.
+        let mut blocks = input.blocks();
+        // The drawing of the stacks comes first, ending in a row of stack numbers
+        let stack_layers = blocks
+            .next()
+            .ok_or_else(|| AocError::parse_file(input.path(), "expected a drawing of the stacks"))?
+            .lines()
+            .map_while(|line| StackLayer::try_from(line).ok())
+            .collect::<Vec<_>>();
+        // Then, after a blank line, the moves
+        let mut operations = vec![];
+        if let Some(moves) = blocks.next() {
+            for (idx, next_line) in moves.lines().enumerate() {
+                let next_op = Operation::try_from(next_line);
+                operations
+                    .push(next_op.map_err(|e| AocError::parse(input.path(), moves.line + idx, e))?);
+            }
+        }
+
+        State::try_from((stack_layers, operations)).map_err(|e| AocError::parse(input.path(), 1, e))
+    }
+
+    fn part_one(&self, state: &Self::Parsed) -> AocResult<Answer> {
+        let new_state = state.apply_moves(CraneModel::Model9000);
+        Ok(new_state.tots().into())
+    }
+
+    fn part_two(&self, state: &Self::Parsed) -> AocResult<Answer> {
+        let new_state2 = state.apply_moves(CraneModel::Model9001);
+        Ok(new_state2.tots().into())
+    }
+}
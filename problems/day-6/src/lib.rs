@@ -0,0 +1,49 @@
+use std::collections::{hash_map::RandomState, HashSet};
+
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Solution};
+
+fn find_run_of_distinct(line: String, run_length: usize) -> Option<usize> {
+    line.chars()
+        .collect::<Vec<char>>()
+        .windows(run_length)
+        .enumerate()
+        .find(|w| {
+            let s: HashSet<char, RandomState> = HashSet::from_iter(w.1.to_owned());
+            s.len() == run_length
+        })
+        // We're tracking the _start_ of the marker by tracking the enumerate value,
+        // here we adjust to the _end_ of the marker
+        .map(|m| (run_length + m.0, m.1.iter().collect::<String>()))
+        .map(|m| m.0)
+}
+
+pub struct Day6 {}
+impl AoCProblem for Day6 {
+    fn name(&self) -> String {
+        "day-6".to_owned()
+    }
+}
+impl Solution for Day6 {
+    // The datastream
+    type Parsed = String;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        input
+            .lines()
+            .next()
+            .map(|line| line.to_owned())
+            .ok_or_else(|| AocError::parse_file(input.path(), "expected a datastream"))
+    }
+
+    fn part_one(&self, line: &Self::Parsed) -> AocResult<Answer> {
+        let marker = find_run_of_distinct(line.clone(), 4)
+            .ok_or_else(|| AocError::invariant("No start-of-packet marker in the datastream"))?;
+        Ok(marker.into())
+    }
+
+    fn part_two(&self, line: &Self::Parsed) -> AocResult<Answer> {
+        let message_marker = find_run_of_distinct(line.clone(), 14)
+            .ok_or_else(|| AocError::invariant("No start-of-message marker in the datastream"))?;
+        Ok(message_marker.into())
+    }
+}
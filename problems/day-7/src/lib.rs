@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use shared::{Answer, AoCProblem, AocError, AocResult, Input, Param, ParamInfo, Solution};
+
+const DISK_SIZE: Param<u64> = Param::new("disk_size", 70000000);
+const UPDATE_SIZE: Param<u64> = Param::new("update_size", 30000000);
+
+fn fan_out<V>(path: String, value: V) -> Vec<(String, V)>
+where
+    V: Copy,
+{
+    let segments = path
+        .split('/')
+        .map(|h| h.to_owned())
+        .collect::<Vec<String>>();
+    let mut result = vec![];
+    for i in 1..segments.len() {
+        let key = segments[0..i].join("/");
+        result.push((key, value));
+    }
+    result
+}
+
+pub struct Filesystem {
+    // Total size of every directory, keyed by its path (the root is "")
+    sums: HashMap<String, u64>,
+    disk_size: u64,
+    // Free space the update needs
+    update_size: u64,
+}
+
+pub struct Day7 {}
+impl AoCProblem for Day7 {
+    fn name(&self) -> String {
+        "day-7".to_owned()
+    }
+}
+impl Solution for Day7 {
+    type Parsed = Filesystem;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let mut current_path_segments = vec![];
+        let mut full_path_file_sizes = HashMap::new();
+        for (idx, text) in input.lines().enumerate() {
+            let parse_error = |message: &str| AocError::parse(input.path(), idx + 1, message);
+            if text.starts_with("$ ls") || text.starts_with("dir") {
+                continue;
+            }
+            if text.starts_with("$ cd ..") {
+                current_path_segments.pop();
+            } else if text.starts_with("$ cd /") {
+                current_path_segments.clear();
+                current_path_segments.push("".to_owned());
+            } else if text.starts_with("$ cd ") {
+                let segment = text.trim()[5..].to_owned();
+                current_path_segments.push(segment);
+            } else {
+                let (raw_file_size, file_name) = text
+                    .trim()
+                    .split_once(' ')
+                    .ok_or_else(|| parse_error("expected '<size> <name>'"))?;
+                let mut key = current_path_segments.join("/");
+                key.push('/');
+                key.push_str(file_name);
+                let file_size = raw_file_size
+                    .parse::<u64>()
+                    .map_err(|e| parse_error(&format!("invalid file size: {}", e)))?;
+                full_path_file_sizes.insert(key, file_size);
+            }
+        }
+        let all_entries = full_path_file_sizes
+            .iter()
+            .flat_map(|entry| fan_out(entry.0.to_owned(), entry.1))
+            .sorted_by_key(|e| e.0.clone())
+            .group_by(|e| e.0.clone());
+        let mut sums = HashMap::new();
+        for (key, group) in all_entries.into_iter() {
+            let sum: u64 = group.map(|g| g.1).sum();
+            sums.insert(key, sum);
+        }
+        Ok(Filesystem {
+            sums,
+            disk_size: DISK_SIZE.get(input)?,
+            update_size: UPDATE_SIZE.get(input)?,
+        })
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![DISK_SIZE.info(), UPDATE_SIZE.info()]
+    }
+
+    fn part_one(&self, filesystem: &Self::Parsed) -> AocResult<Answer> {
+        let sums_under_100000 = filesystem
+            .sums
+            .iter()
+            .filter(|kv| *kv.1 <= 100000)
+            .collect::<HashMap<&String, &u64>>();
+        let part_one = sums_under_100000
+            .iter()
+            .map(|kv| kv.1.to_owned())
+            .sum::<u64>();
+        Ok(part_one.into())
+    }
+
+    fn part_two(&self, filesystem: &Self::Parsed) -> AocResult<Answer> {
+        let used = filesystem
+            .sums
+            .get("")
+            .ok_or_else(|| AocError::invariant("No files were listed under the root"))?;
+        let free = filesystem.disk_size.saturating_sub(*used);
+        let to_free = filesystem.update_size.saturating_sub(free);
+        let (_to_delete, will_free) = filesystem
+            .sums
+            .iter()
+            .sorted_by_key(|kv| kv.1)
+            .find(|kv| *kv.1 > to_free)
+            .ok_or_else(|| AocError::invariant("No directory frees enough space"))?;
+        Ok((*will_free).into())
+    }
+}
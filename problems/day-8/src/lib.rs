@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+
+use shared::{
+    grid::{Grid, Pos},
+    Answer, AoCProblem, AocResult, Input, Solution,
+};
+
+// Each tree's height, alongside its (row, col) in the original grid
+type IndexedGrid = Grid<(Pos, i32)>;
+
+// For a given direction, an index is visible if no prior value is greater
+fn visible_from_outside(run: &[(Pos, i32)]) -> Vec<Pos> {
+    let mut result = vec![];
+    let mut max_in_run: i32 = -1;
+
+    for &(idx, value) in run {
+        if value > max_in_run {
+            result.push(idx);
+            max_in_run = value;
+        }
+    }
+
+    result
+}
+
+fn visible_from_any_side(grid: &IndexedGrid) -> Vec<Pos> {
+    let mut result = vec![];
+    for run in grid.rows() {
+        let visible_in_run = visible_from_outside(run);
+        result.extend(visible_in_run);
+    }
+    result
+}
+
+fn view_score(run: &[(Pos, i32)]) -> Vec<(Pos, usize)> {
+    let mut result = vec![];
+    // Look at sliding windows, starting from the head of the run to the end, then the second element to the end, etc
+    for start in 0..run.len() {
+        let (start_position, start_height) = run[start];
+        let mut score = 0;
+        for run_item in run.iter().skip(start + 1) {
+            score += 1;
+            let height_to_check = run_item.1;
+            if height_to_check >= start_height {
+                break;
+            }
+        }
+        result.push((start_position, score));
+    }
+    result
+}
+
+fn all_view_scores(grid: &IndexedGrid) -> Vec<(Pos, usize)> {
+    let mut result = vec![];
+    for row in grid.rows() {
+        result.extend(view_score(row));
+    }
+    result
+}
+
+// The indexed grid as seen from each side: right to left, left to right, top to bottom, and bottom to top
+fn views(grid: &Grid<i32>) -> Vec<IndexedGrid> {
+    let indexed = grid.map(|pos, height| (pos, *height));
+    vec![
+        indexed.flip_horizontal(),
+        indexed.clone(),
+        indexed.transpose(),
+        indexed.transpose().flip_horizontal(),
+    ]
+}
+
+pub struct Day8 {}
+impl AoCProblem for Day8 {
+    fn name(&self) -> String {
+        "day-8".to_owned()
+    }
+}
+impl Solution for Day8 {
+    // Tree heights, by row
+    type Parsed = Grid<i32>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        Grid::parse(input, |c| {
+            c.to_digit(10)
+                .map(|d| d as i32)
+                .ok_or_else(|| format!("expected a digit, found '{}'", c))
+        })
+    }
+
+    fn part_one(&self, grid: &Self::Parsed) -> AocResult<Answer> {
+        let mut visible_trees = HashSet::new();
+        for direction in views(grid) {
+            visible_trees.extend(visible_from_any_side(&direction));
+        }
+        Ok(visible_trees.len().into())
+    }
+
+    fn part_two(&self, grid: &Self::Parsed) -> AocResult<Answer> {
+        let mut view_scores = vec![];
+        for direction in views(grid) {
+            view_scores.extend(all_view_scores(&direction));
+        }
+
+        let mut aggregated_view_scores: HashMap<Pos, usize> = HashMap::new();
+        for view_score in view_scores {
+            let previous_score = aggregated_view_scores.get(&view_score.0).unwrap_or(&1);
+            aggregated_view_scores.insert(view_score.0, previous_score * view_score.1);
+        }
+        let mut highest_view_scores: Vec<(Pos, usize)> =
+            aggregated_view_scores.into_iter().collect();
+        highest_view_scores.sort_by_key(|e| e.1);
+        highest_view_scores.reverse();
+        Ok(highest_view_scores[0].1.into())
+    }
+}
@@ -0,0 +1,106 @@
+use shared::{
+    geom::{Direction, Point2},
+    sparse::SparseGrid,
+    Answer, AoCProblem, AocResult, Input, Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Command(Direction, usize);
+impl TryFrom<&str> for Command {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parts = value
+            .trim()
+            .split_once(' ')
+            .ok_or("Unable to split on ' '; each line should have a space in it")?;
+        let direction = match parts.0.chars().collect::<Vec<_>>()[..] {
+            [c @ ('U' | 'D' | 'L' | 'R')] => Direction::try_from(c),
+            _ => Err("Unrecognized direction".to_owned()),
+        }?;
+        let distance = parts
+            .1
+            .parse::<usize>()
+            .map_err(|_| "Unable to parse distance")?;
+        Ok(Command(direction, distance))
+    }
+}
+
+type Position = Point2<i64>;
+
+#[derive(Debug)]
+struct VisitState {
+    nodes: Vec<Position>,
+    /// Every point the tail has been
+    visited: SparseGrid<()>,
+}
+impl VisitState {
+    fn new(node_count: usize) -> Self {
+        VisitState {
+            nodes: vec![Position::default(); node_count],
+            visited: SparseGrid::from_iter([(Position::default(), ())]),
+        }
+    }
+    fn updated_position(lead: Position, tail: Position) -> Position {
+        // Move one step towards the lead along each axis it differs on, which is diagonally
+        // when they're in neither the same row nor the same column
+        let delta = lead - tail;
+        tail + Position::new(delta.x.signum(), delta.y.signum())
+    }
+    fn visit(&mut self, command: Command) {
+        let Command(direction, _) = command;
+        let mut new_head = self.nodes[0].step(direction);
+        let mut updated_nodes = vec![new_head];
+
+        for previous_tail in self.nodes.iter().skip(1) {
+            let new_tail = if new_head.chebyshev(previous_tail) <= 1 {
+                *previous_tail
+            } else {
+                Self::updated_position(new_head, *previous_tail)
+            };
+            updated_nodes.push(new_tail);
+            new_head = new_tail;
+        }
+
+        let tail = updated_nodes.last().expect("Should be a last element");
+        self.visited.insert(*tail, ());
+        self.nodes = updated_nodes;
+    }
+}
+
+pub struct Day9 {}
+impl AoCProblem for Day9 {
+    fn name(&self) -> String {
+        "day-9".to_owned()
+    }
+}
+impl Solution for Day9 {
+    // Every command, split into single steps
+    type Parsed = Vec<Command>;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed> {
+        let commands: Vec<Command> = input
+            .parse_lines(|l| Command::try_from(l))?
+            .into_iter()
+            // Turn e.g. (R, 1) into [(R, 1), (R,1), (R, 1)] to make them easier to process
+            .flat_map(|c| std::iter::repeat_n(Command(c.0, 1), c.1))
+            .collect();
+        Ok(commands)
+    }
+
+    fn part_one(&self, commands: &Self::Parsed) -> AocResult<Answer> {
+        let mut visit_state = VisitState::new(2);
+        for command in commands.clone() {
+            visit_state.visit(command);
+        }
+        Ok(visit_state.visited.len().into())
+    }
+
+    fn part_two(&self, commands: &Self::Parsed) -> AocResult<Answer> {
+        let mut visit_state_2 = VisitState::new(10);
+        for command in commands.iter() {
+            visit_state_2.visit(command.to_owned());
+        }
+        Ok(visit_state_2.visited.len().into())
+    }
+}
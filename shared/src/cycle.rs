@@ -0,0 +1,189 @@
+//! Finding where a deterministic process starts repeating, and skipping ahead with it.
+//!
+//! A process is a state and a `step` that advances it in place. States are compared through a
+//! `key`, which only needs to capture what decides the future: two states with equal keys must
+//! go on to produce equal keys forever. The searches give up after a given number of steps, since
+//! a process whose keys never repeat would otherwise keep them looking forever.
+
+/// After `prefix` steps the process enters a loop, and every `period` steps it's back where it
+/// started
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+impl Cycle {
+    /// The earliest step whose state has the same key as the state after `n` steps
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    /// The value of `metric` after `n` steps, assuming it changes by the same amount over every
+    /// lap of the loop. Only the prefix and one lap are actually simulated.
+    pub fn extrapolate<S, F, M>(&self, start: &S, mut step: F, metric: M, n: usize) -> i64
+    where
+        S: Clone,
+        F: FnMut(&mut S),
+        M: Fn(&S) -> i64,
+    {
+        let mut state = start.clone();
+        let mut metrics = vec![metric(&state)];
+        for _ in 0..n.min(self.prefix + self.period) {
+            step(&mut state);
+            metrics.push(metric(&state));
+        }
+        if n <= self.prefix + self.period {
+            return metrics[n];
+        }
+        let laps = ((n - self.prefix) / self.period) as i64;
+        let per_lap = metrics[self.prefix + self.period] - metrics[self.prefix];
+        metrics[self.equivalent_step(n)] + laps * per_lap
+    }
+}
+
+/// Floyd's tortoise and hare: the hare runs at twice the tortoise's speed until they meet inside
+/// the loop. `None` if they haven't met after the hare takes `max_steps` steps.
+pub fn floyd<S, K, F, G>(start: &S, mut step: F, key: G, max_steps: usize) -> Option<Cycle>
+where
+    S: Clone,
+    K: PartialEq,
+    F: FnMut(&mut S),
+    G: Fn(&S) -> K,
+{
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    let mut steps = 2;
+    step(&mut tortoise);
+    step(&mut hare);
+    step(&mut hare);
+    while key(&tortoise) != key(&hare) {
+        if steps >= max_steps {
+            return None;
+        }
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+        steps += 2;
+    }
+
+    // The hare is now a whole number of laps ahead, so restarting the tortoise and moving both
+    // at the same speed has them meet where the loop begins
+    let mut tortoise = start.clone();
+    let mut prefix = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    step(&mut hare);
+    while key(&tortoise) != key(&hare) {
+        step(&mut hare);
+        period += 1;
+    }
+    Some(Cycle { prefix, period })
+}
+
+/// Brent's algorithm: the tortoise teleports to the hare at every power of two, which finds the
+/// period directly and takes fewer steps than Floyd's. `None` if the hare takes `max_steps` steps
+/// without finding a repeat.
+pub fn brent<S, K, F, G>(start: &S, mut step: F, key: G, max_steps: usize) -> Option<Cycle>
+where
+    S: Clone,
+    K: PartialEq,
+    F: FnMut(&mut S),
+    G: Fn(&S) -> K,
+{
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = key(start);
+    let mut hare = start.clone();
+    let mut steps = 1;
+    step(&mut hare);
+    while tortoise != key(&hare) {
+        if steps >= max_steps {
+            return None;
+        }
+        if power == period {
+            tortoise = key(&hare);
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        steps += 1;
+        period += 1;
+    }
+
+    // Start the hare a lap ahead; they meet where the loop begins
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..period {
+        step(&mut hare);
+    }
+    let mut prefix = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        prefix += 1;
+    }
+    Some(Cycle { prefix, period })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 3, 4, 5, ... alongside the running total
+    fn step(state: &mut (i64, i64)) {
+        state.0 = if state.0 == 5 { 3 } else { state.0 + 1 };
+        state.1 += state.0;
+    }
+
+    #[test]
+    fn should_find_prefix_and_period() {
+        let expected = Cycle {
+            prefix: 3,
+            period: 3,
+        };
+        assert_eq!(floyd(&(0, 0), step, |s| s.0, 100), Some(expected));
+        assert_eq!(brent(&(0, 0), step, |s| s.0, 100), Some(expected));
+
+        // A pseudo-random walk; both searches should agree
+        let lcg = |x: &mut u64| *x = (*x * 75 + 74) % 1009;
+        assert_eq!(floyd(&7, lcg, |x| *x, 10000), brent(&7, lcg, |x| *x, 10000));
+        assert_eq!(
+            Some(Cycle {
+                prefix: 0,
+                period: 1
+            }),
+            brent(&1, |_: &mut u8| {}, |x| *x, 100)
+        );
+
+        // Counting up never repeats
+        let count = |x: &mut u64| *x += 1;
+        assert_eq!(floyd(&0, count, |x| *x, 1000), None);
+        assert_eq!(brent(&0, count, |x| *x, 1000), None);
+    }
+
+    #[test]
+    fn should_extrapolate_metric() {
+        let cycle = brent(&(0, 0), step, |s| s.0, 100).expect("Should find a cycle");
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(10), 4);
+        let mut state = (0, 0);
+        for n in 0..50 {
+            assert_eq!(cycle.extrapolate(&(0, 0), step, |s| s.1, n), state.1);
+            step(&mut state);
+        }
+        assert_eq!(
+            cycle.extrapolate(&(0, 0), step, |s| s.1, 1_000_000_000_000),
+            // The first two steps, a lap for every three after that, and two more
+            3 + 333_333_333_332 * 12 + 7
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Errors raised while reading, parsing, or solving a day's input.
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    path::Path,
+};
+
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file could not be read
+    Io { path: String, source: io::Error },
+    /// The input was read but is not in the expected format
+    Parse {
+        path: String,
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    /// The input parsed, but breaks an assumption the solution relies on
+    Invariant(String),
+    /// The input could not be downloaded from the puzzle server
+    Fetch { url: String, message: String },
+}
+impl AocError {
+    pub fn io<P>(path: P, source: io::Error) -> AocError
+    where
+        P: AsRef<Path>,
+    {
+        AocError::Io {
+            path: path.as_ref().display().to_string(),
+            source,
+        }
+    }
+
+    /// A parse error on the given (1-based) line of `path`
+    pub fn parse<M>(path: &str, line: usize, message: M) -> AocError
+    where
+        M: Display,
+    {
+        AocError::Parse {
+            path: path.to_owned(),
+            line: Some(line),
+            column: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// A parse error that can't be pinned to a single line, e.g. an empty file
+    pub fn parse_file<M>(path: &str, message: M) -> AocError
+    where
+        M: Display,
+    {
+        AocError::Parse {
+            path: path.to_owned(),
+            line: None,
+            column: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Narrow a parse error down to a (1-based) column of its line
+    pub fn at_column(self, column: usize) -> AocError {
+        match self {
+            AocError::Parse {
+                path,
+                line,
+                message,
+                ..
+            } => AocError::Parse {
+                path,
+                line,
+                column: Some(column),
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub fn fetch<M>(url: &str, message: M) -> AocError
+    where
+        M: Display,
+    {
+        AocError::Fetch {
+            url: url.to_owned(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn invariant<M>(message: M) -> AocError
+    where
+        M: Display,
+    {
+        AocError::Invariant(message.to_string())
+    }
+}
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "{}: {}", path, source),
+            AocError::Parse {
+                path,
+                line,
+                column,
+                message,
+            } => {
+                write!(f, "{}", path)?;
+                if let Some(line) = line {
+                    write!(f, ":{}", line)?;
+                }
+                if let Some(column) = column {
+                    write!(f, ":{}", column)?;
+                }
+                write!(f, ": {}", message)
+            }
+            AocError::Invariant(message) => write!(f, "{}", message),
+            AocError::Fetch { url, message } => write!(f, "{}: {}", url, message),
+        }
+    }
+}
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_display_location() {
+        let error = AocError::parse("data/day-15/input.txt", 7, "expected ' y='");
+        assert_eq!(
+            format!("{}", error),
+            "data/day-15/input.txt:7: expected ' y='"
+        );
+        assert_eq!(
+            format!("{}", error.at_column(12)),
+            "data/day-15/input.txt:7:12: expected ' y='"
+        );
+        assert_eq!(
+            format!("{}", AocError::parse_file("data/day-17/test.txt", "empty")),
+            "data/day-17/test.txt: empty"
+        );
+    }
+}
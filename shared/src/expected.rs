@@ -0,0 +1,245 @@
+//! Known-correct answers for a day's inputs, recorded in `data/day-{n}/expected.toml`.
+//!
+//! Each table is named after the stem of the input file it describes:
+//!
+//! ```toml
+//! [test]
+//! part1 = 24000
+//! part2 = 45000
+//! ```
+//!
+//! Integers are numeric answers, strings are text answers (use a multi-line literal string
+//! for renderings). Parts without a known answer are simply left out. An input's table can
+//! also hold the `params` its solution needs (see [`crate::params`]).
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    time::Duration,
+};
+
+use toml::{Table, Value};
+
+use crate::{Answer, AocError, AocResult, Params};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub params: Params,
+}
+
+impl ExpectedAnswers {
+    pub fn part(&self, part: usize) -> &Option<Answer> {
+        match part {
+            1 => &self.part1,
+            2 => &self.part2,
+            _ => panic!("There are only two parts, got part {}", part),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Expected(HashMap<String, ExpectedAnswers>);
+impl Expected {
+    /// Load expected answers from a file. A missing file means nothing is known yet.
+    pub fn load<P>(path: P) -> AocResult<Expected>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Expected::default());
+        }
+        let text = fs::read_to_string(path).map_err(|e| AocError::io(path, e))?;
+        Expected::try_from(text.as_str())
+            .map_err(|e| AocError::parse_file(&path.display().to_string(), e))
+    }
+
+    /// Expected answers for the input file at `path`, looked up by its file stem
+    pub fn for_input(&self, path: &str) -> ExpectedAnswers {
+        self.0.get(&input_name(path)).cloned().unwrap_or_default()
+    }
+}
+impl TryFrom<&str> for Expected {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let table = value.parse::<Table>().map_err(|e| e.to_string())?;
+        let mut inputs = HashMap::new();
+        for (input, parts) in table {
+            let parts = parts
+                .as_table()
+                .ok_or(format!("[{}] should be a table of parts", input))?;
+            let part = |key: &str| -> Result<Option<Answer>, String> {
+                match parts.get(key) {
+                    None => Ok(None),
+                    Some(Value::Integer(n)) => Ok(Some(Answer::Number(*n))),
+                    Some(Value::String(s)) => Ok(Some(Answer::Text(s.to_owned()))),
+                    Some(other) => Err(format!(
+                        "{}.{} should be an integer or a string, found {}",
+                        input,
+                        key,
+                        other.type_str()
+                    )),
+                }
+            };
+            let params = match parts.get("params") {
+                None => Params::new(),
+                Some(Value::Table(params)) => params
+                    .iter()
+                    .map(|(name, value)| match value {
+                        Value::Integer(n) => Ok((name.as_str(), *n)),
+                        other => Err(format!(
+                            "{}.params.{} should be an integer, found {}",
+                            input,
+                            name,
+                            other.type_str()
+                        )),
+                    })
+                    .collect::<Result<Params, String>>()?,
+                Some(other) => {
+                    return Err(format!(
+                        "{}.params should be a table, found {}",
+                        input,
+                        other.type_str()
+                    ))
+                }
+            };
+            let expected = ExpectedAnswers {
+                part1: part("part1")?,
+                part2: part("part2")?,
+                params,
+            };
+            inputs.insert(input, expected);
+        }
+        Ok(Expected(inputs))
+    }
+}
+
+/// The name an input file is recorded under, e.g. `test` for `data/day-1/test.txt`
+pub fn input_name(path: &str) -> String {
+    Path::new(path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_owned())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(Answer),
+    Missing,
+    /// The input couldn't be solved at all
+    Error(String),
+}
+
+/// The outcome of comparing one part's answer with its expected answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Verification {
+    pub input: String,
+    pub part: usize,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// How long the part took to solve, if it was solved
+    pub duration: Option<Duration>,
+}
+impl Verification {
+    pub fn check(input: &str, part: usize, answer: Answer, expected: Option<Answer>) -> Self {
+        let verdict = match expected {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected),
+        };
+        Verification {
+            input: input.to_owned(),
+            part,
+            answer,
+            verdict,
+            duration: None,
+        }
+    }
+
+    pub fn error(input: &str, part: usize, error: &AocError) -> Self {
+        Verification {
+            input: input.to_owned(),
+            part,
+            answer: Answer::Unsolved,
+            verdict: Verdict::Error(error.to_string()),
+            duration: None,
+        }
+    }
+}
+impl Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.verdict {
+            Verdict::Pass => "PASS",
+            Verdict::Fail(_) => "FAIL",
+            Verdict::Missing => "MISSING",
+            Verdict::Error(_) => "ERROR",
+        };
+        write!(f, "{:<7} {} part {}", label, self.input, self.part)?;
+        match &self.verdict {
+            Verdict::Fail(expected) => {
+                write!(f, ": got {}, expected {}", self.answer, expected)
+            }
+            Verdict::Error(e) => write!(f, ": {}", e),
+            _ => write!(f, ": {}", self.answer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPECTED: &str = "
+[test]
+part1 = 13140
+part2 = '''
+##..
+###.'''
+
+[input]
+part1 = 11720
+params = { row = 10 }
+";
+
+    #[test]
+    fn should_parse_expected_answers() {
+        let expected = Expected::try_from(EXPECTED).expect("Should parse");
+        let test = expected.for_input("data/day-10/test.txt");
+        assert_eq!(test.part1, Some(Answer::Number(13140)));
+        assert_eq!(test.part2, Some(Answer::Text("##..\n###.".to_owned())));
+        let input = expected.for_input("data/day-10/input.txt");
+        assert_eq!(input.part2, None);
+        assert_eq!(input.params.get("row"), Some(10));
+        assert!(test.params.is_empty());
+        assert_eq!(
+            expected.for_input("data/day-10/other.txt"),
+            ExpectedAnswers::default()
+        );
+    }
+
+    #[test]
+    fn should_reject_unsupported_values() {
+        assert!(Expected::try_from("[test]\npart1 = 1.5").is_err());
+        assert!(Expected::try_from("test = 1").is_err());
+        assert!(Expected::try_from("[test]\nparams = { row = 'x' }").is_err());
+    }
+
+    #[test]
+    fn should_check_answers() {
+        let pass = Verification::check("test", 1, 24.into(), Some(24.into()));
+        assert_eq!(pass.verdict, Verdict::Pass);
+        let fail = Verification::check("test", 2, 64.into(), Some(8.into()));
+        assert_eq!(fail.verdict, Verdict::Fail(8.into()));
+        assert_eq!(
+            format!("{}", fail),
+            "FAIL    test part 2: got 64, expected 8"
+        );
+        let missing = Verification::check("input", 2, 64.into(), None);
+        assert_eq!(missing.verdict, Verdict::Missing);
+    }
+}
@@ -0,0 +1,181 @@
+//! Downloads a day's puzzle input and caches it as `data/day-{n}/input.txt`.
+//!
+//! Inputs are requested from `{base_url}/day/{n}/input` with the account's session token as a
+//! cookie. Once an input is cached it is never fetched again; delete the file to refetch it.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{AocError, AocResult};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2022";
+/// Environment variable holding the session token
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the puzzle server, e.g. for a mirror
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = "github.com/devonterr/advent-of-code-2022 input fetcher";
+
+pub struct InputProvider {
+    base_url: String,
+    session: String,
+    data_dir: PathBuf,
+}
+impl InputProvider {
+    pub fn new(base_url: &str, session: &str) -> InputProvider {
+        InputProvider {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            data_dir: PathBuf::from("data"),
+        }
+    }
+
+    /// Configured from `AOC_SESSION`, and `AOC_BASE_URL` if it's set
+    pub fn from_env() -> AocResult<InputProvider> {
+        let session = env::var(SESSION_VAR).map_err(|_| {
+            AocError::invariant(format!(
+                "Set {} to your session cookie to fetch inputs",
+                SESSION_VAR
+            ))
+        })?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        Ok(InputProvider::new(&base_url, &session))
+    }
+
+    /// Cache inputs under `data_dir` instead of `data`
+    pub fn with_data_dir<P>(self, data_dir: P) -> InputProvider
+    where
+        P: AsRef<Path>,
+    {
+        InputProvider {
+            data_dir: data_dir.as_ref().to_owned(),
+            ..self
+        }
+    }
+
+    pub fn input_path(&self, day: usize) -> PathBuf {
+        self.data_dir.join(format!("day-{}", day)).join("input.txt")
+    }
+
+    /// An input counts as cached once it has any content; `new` leaves an empty placeholder
+    pub fn is_cached(&self, day: usize) -> bool {
+        fs::metadata(self.input_path(day))
+            .map(|m| m.len() > 0)
+            .unwrap_or(false)
+    }
+
+    /// The path to the day's input, downloading it first if it isn't cached yet
+    pub fn fetch(&self, day: usize) -> AocResult<PathBuf> {
+        let path = self.input_path(day);
+        if self.is_cached(day) {
+            return Ok(path);
+        }
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let body = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => AocError::fetch(
+                    &url,
+                    format!("server responded {}; is the session token valid?", status),
+                ),
+                ureq::Error::Transport(t) => AocError::fetch(&url, t),
+            })?
+            .into_string()
+            .map_err(|e| AocError::fetch(&url, e))?;
+        if body.is_empty() {
+            return Err(AocError::fetch(&url, "server returned an empty input"));
+        }
+
+        // Write to a scratch file first so an interrupted download is never mistaken for a cache
+        let dir = path.parent().expect("Should have a day directory");
+        fs::create_dir_all(dir).map_err(|e| AocError::io(dir, e))?;
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, body).map_err(|e| AocError::io(&partial, e))?;
+        fs::rename(&partial, &path).map_err(|e| AocError::io(&path, e))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::*;
+
+    /// A local server answering every request with `status` and `body`, recording each
+    /// request's line and cookie
+    fn stub_server(status: u16, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Should bind a local port");
+        let url = format!("http://{}", listener.local_addr().expect("Should be bound"));
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.expect("Should accept");
+                let head = BufReader::new(&stream)
+                    .lines()
+                    .map(|l| l.expect("Should read request"))
+                    .take_while(|l| !l.is_empty())
+                    .filter(|l| !l.contains(':') || l.starts_with("Cookie:"))
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+                recorded.lock().expect("Should lock").push(head);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .expect("Should respond");
+            }
+        });
+        (url, requests)
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn should_fetch_once_then_use_cache() {
+        let (url, requests) = stub_server(200, "1000\n2000\n");
+        let dir = scratch_dir("cache");
+        let provider = InputProvider::new(&url, "abc123\n").with_data_dir(&dir);
+
+        let path = provider.fetch(1).expect("Should fetch");
+        assert_eq!(path, dir.join("day-1/input.txt"));
+        assert_eq!(
+            fs::read_to_string(&path).expect("Should read"),
+            "1000\n2000\n"
+        );
+        assert!(provider.fetch(1).is_ok());
+        assert_eq!(
+            *requests.lock().expect("Should lock"),
+            vec!["GET /day/1/input HTTP/1.1 | Cookie: session=abc123"]
+        );
+
+        fs::remove_dir_all(&dir).expect("Should clean up");
+    }
+
+    #[test]
+    fn should_not_cache_failures() {
+        let (url, _) = stub_server(400, "Please log in");
+        let dir = scratch_dir("failure");
+        let provider = InputProvider::new(&url, "expired").with_data_dir(&dir);
+
+        let error = provider.fetch(7).expect_err("Should fail");
+        assert!(error.to_string().contains("server responded 400"));
+        assert!(!provider.is_cached(7));
+    }
+}
@@ -0,0 +1,327 @@
+//! Points and offsets on the plane and in space, with the distances and neighbors puzzles use.
+//!
+//! A `Point2`/`Point3` doubles as a vector: adding two points offsets one by the other. The
+//! `y` axis grows downward, matching how grids are drawn, so `Direction::Up` is `y - 1`.
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// `|a - b|`, for signed and unsigned types alike
+fn abs_diff<T>(a: T, b: T) -> T
+where
+    T: Copy + Ord + Sub<Output = T>,
+{
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+impl<T> Point2<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    /// The taxicab distance: steps needed moving only orthogonally
+    pub fn manhattan(&self, other: &Point2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The chessboard distance: steps needed when diagonal moves are allowed too
+    pub fn chebyshev(&self, other: &Point2<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// The smallest `x` and smallest `y` of the two points
+    pub fn component_min(&self, other: &Point2<T>) -> Point2<T> {
+        Point2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// The largest `x` and largest `y` of the two points
+    pub fn component_max(&self, other: &Point2<T>) -> Point2<T> {
+        Point2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+impl<T> Point2<T>
+where
+    T: Copy + Add<Output = T> + From<i8>,
+{
+    /// The point one step away in `direction`
+    pub fn step(&self, direction: Direction) -> Point2<T> {
+        *self + direction.offset()
+    }
+
+    /// Up, right, down, and left of this point
+    pub fn neighbors4(&self) -> [Point2<T>; 4] {
+        Direction::ALL.map(|d| self.step(d))
+    }
+
+    /// The orthogonal neighbors, plus the four diagonals
+    pub fn neighbors8(&self) -> [Point2<T>; 8] {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .map(|(x, y)| *self + Point2::new(T::from(x), T::from(y)))
+    }
+}
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+impl<T: Copy + Add<Output = T>> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<T: Copy + Sub<Output = T>> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Self::Output {
+        Point2::new(-self.x, -self.y)
+    }
+}
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+impl<T> Point3<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn manhattan(&self, other: &Point3<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Point3<T>) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+
+    pub fn component_min(&self, other: &Point3<T>) -> Point3<T> {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn component_max(&self, other: &Point3<T>) -> Point3<T> {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+impl<T> Point3<T>
+where
+    T: Copy + Add<Output = T> + From<i8>,
+{
+    /// The six points sharing a face with this one
+    pub fn neighbors6(&self) -> [Point3<T>; 6] {
+        [
+            (1, 0, 0),
+            (0, 1, 0),
+            (0, 0, 1),
+            (-1, 0, 0),
+            (0, -1, 0),
+            (0, 0, -1),
+        ]
+        .map(|(x, y, z)| *self + Point3::new(T::from(x), T::from(y), T::from(z)))
+    }
+}
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+impl<T: Copy + Add<Output = T>> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<T: Copy + Sub<Output = T>> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Self::Output {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// One of the four orthogonal directions on the plane
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+impl Direction {
+    /// Clockwise, starting from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// A one-step offset in this direction
+    pub fn offset<T>(&self) -> Point2<T>
+    where
+        T: From<i8>,
+    {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        Direction::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        Direction::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn opposite(&self) -> Direction {
+        Direction::ALL[(*self as usize + 2) % 4]
+    }
+}
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /// Either a letter (`U`, `R`, `D`, `L`) or an arrow (`^`, `>`, `v`, `<`)
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | '^' => Ok(Direction::Up),
+            'R' | '>' => Ok(Direction::Right),
+            'D' | 'v' => Ok(Direction::Down),
+            'L' | '<' => Ok(Direction::Left),
+            _ => Err(format!("expected a direction, found '{}'", value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_measure_distances() {
+        let a = Point2::new(8, 7);
+        let b = Point2::new(2, 10);
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!(Point2::new(3usize, 1).manhattan(&Point2::new(1, 4)), 5);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(&Point3::new(-1, 2, 0)), 5);
+        assert_eq!(a + b, Point2::new(10, 17));
+        assert_eq!(a - b * 2, Point2::new(4, -13));
+        assert_eq!(-a, Point2::new(-8, -7));
+    }
+
+    #[test]
+    fn should_step_and_turn() {
+        let origin = Point2::<i64>::default();
+        assert_eq!(origin.step(Direction::Up), Point2::new(0, -1));
+        assert_eq!(
+            origin.neighbors4().to_vec(),
+            vec![
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0)
+            ]
+        );
+        assert!(origin
+            .neighbors8()
+            .iter()
+            .all(|n| n.chebyshev(&origin) == 1));
+        assert_eq!(Point3::new(0i64, 0, 0).neighbors6().len(), 6);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert!(Direction::try_from('x').is_err());
+    }
+}
@@ -0,0 +1,356 @@
+//! A rectangular grid of cells, stored row by row.
+//!
+//! Positions are `(row, col)` pairs counted from the top left. Directions are `(row, col)`
+//! steps, so `(-1, 0)` is up and `(1, 1)` is down and to the right.
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{AocError, AocResult, Input};
+
+/// A `(row, col)` position
+pub type Pos = (usize, usize);
+
+/// Up, right, down, and left
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// The orthogonal directions, plus the four diagonals
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A grid whose cells are each computed from their position
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Grid<T>
+    where
+        F: FnMut(Pos) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Fails if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, String> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if let Some(idx) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "Row {} has {} cells, expected {} like the first row",
+                idx,
+                rows[idx].len(),
+                width
+            ));
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// One cell per character, one row per line. Ragged or empty input is a parse error.
+    pub fn parse<E, F>(input: &Input, mut parse_cell: F) -> AocResult<Grid<T>>
+    where
+        E: Display,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut rows = vec![];
+        for (i, line) in input.lines().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(j, c)| {
+                    parse_cell(c)
+                        .map_err(|e| AocError::parse(input.path(), i + 1, e).at_column(j + 1))
+                })
+                .collect::<AocResult<Vec<T>>>()?;
+            if let Some(first) = rows.first().map(|first: &Vec<T>| first.len()) {
+                if row.len() != first {
+                    return Err(AocError::parse(
+                        input.path(),
+                        i + 1,
+                        format!("expected {} cells, like the first row", first),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(AocError::parse_file(input.path(), "expected a grid"));
+        }
+        Ok(Grid::from_rows(rows).expect("Should have checked every row's length"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// The position one `step` away from `pos`, if that's still on the grid
+    pub fn step(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let row = row.checked_add_signed(d_row).filter(|r| *r < self.height)?;
+        let col = col.checked_add_signed(d_col).filter(|c| *c < self.width)?;
+        Some((row, col))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell alongside its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row by row, whose cell matches
+    pub fn find<F>(&self, predicate: F) -> Option<Pos>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The orthogonal neighbors of `pos` that are on the grid
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that are on the grid
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks(0) panics, and a zero-width grid has no cells to split anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(col)
+            .step_by(self.width.max(1))
+            .take(if col < self.width { self.height } else { 0 })
+    }
+
+    /// The cells from `from` (exclusive) to the edge of the grid, taking `step` each time.
+    /// Diagonal steps give the grid's diagonals.
+    pub fn ray(&self, from: Pos, step: (isize, isize)) -> impl Iterator<Item = (Pos, &T)> {
+        let mut pos = Some(from);
+        std::iter::from_fn(move || {
+            let next = self.step(pos?, step);
+            pos = next;
+            next.map(|p| (p, &self[p]))
+        })
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Pos, &T) -> U,
+    {
+        Grid::from_fn(self.width, self.height, |pos| f(pos, &self[pos]))
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(row, self.width - 1 - col)].clone()
+        })
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(self.height - 1 - row, col)].clone()
+        })
+    }
+}
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {:?} should be on the grid", pos))
+    }
+}
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {:?} should be on the grid", pos))
+    }
+}
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse(
+            &Input::from_text("example", "abc\ndef\n"),
+            Ok::<char, String>,
+        )
+        .expect("Should parse")
+    }
+
+    #[test]
+    fn should_parse_and_render() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(format!("{}", grid), "abc\ndef");
+
+        let digits = Grid::parse(&Input::from_text("example", "12\n3x\n"), |c| {
+            c.to_digit(10).ok_or("expected a digit")
+        });
+        assert_eq!(
+            digits.map_err(|e| e.to_string()),
+            Err("example:2:2: expected a digit".to_owned())
+        );
+        let ragged = Grid::parse(&Input::from_text("example", "12\n3\n"), Ok::<char, String>);
+        assert!(ragged.is_err());
+    }
+
+    #[test]
+    fn should_find_neighbors_on_grid() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn should_view_rows_columns_and_rays() {
+        let grid = example();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![((1, 1), &'e')]
+        );
+        assert_eq!(
+            grid.ray((1, 2), (0, -1))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "ed"
+        );
+    }
+
+    #[test]
+    fn should_transform() {
+        let grid = example();
+        assert_eq!(format!("{}", grid.transpose()), "ad\nbe\ncf");
+        assert_eq!(format!("{}", grid.rotate_clockwise()), "da\neb\nfc");
+        assert_eq!(format!("{}", grid.rotate_counterclockwise()), "cf\nbe\nad");
+        assert_eq!(format!("{}", grid.flip_horizontal()), "cba\nfed");
+        assert_eq!(format!("{}", grid.flip_vertical()), "def\nabc");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,196 @@
+//! A day's input, read once and then handed out as borrowed lines and blocks.
+//!
+//! Inputs come from a file, stdin (the path `-`), or an in-memory string, which is handy for
+//! testing a solution against a snippet without writing it to disk.
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    iter::{Enumerate, Peekable},
+    path::Path,
+    str::Lines,
+};
+
+use crate::{AocError, AocResult, Params};
+
+/// The path that reads from stdin
+pub const STDIN: &str = "-";
+
+pub struct Input {
+    /// Where the input came from, for error messages
+    path: String,
+    text: String,
+    params: Params,
+}
+impl Input {
+    /// Read the whole of `path`, or stdin if the path is `-`
+    pub fn read<P>(path: P) -> AocResult<Input>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        if path == Path::new(STDIN) {
+            return Input::from_stdin();
+        }
+        let text = fs::read_to_string(path).map_err(|e| AocError::io(path, e))?;
+        Ok(Input {
+            path: path.display().to_string(),
+            text,
+            params: Params::new(),
+        })
+    }
+
+    pub fn from_stdin() -> AocResult<Input> {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| AocError::io("<stdin>", e))?;
+        Ok(Input {
+            path: "<stdin>".to_owned(),
+            text,
+            params: Params::new(),
+        })
+    }
+
+    /// An input held in memory; `name` stands in for the path in error messages
+    pub fn from_text(name: &str, text: &str) -> Input {
+        Input {
+            path: name.to_owned(),
+            text: text.to_owned(),
+            params: Params::new(),
+        }
+    }
+
+    /// The same input, with `params` for the solution to read
+    pub fn with_params(self, params: Params) -> Input {
+        Input { params, ..self }
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// A parameter the solution can't do without
+    pub fn param(&self, name: &str) -> AocResult<i64> {
+        self.params.get(name).ok_or_else(|| {
+            AocError::parse_file(
+                &self.path,
+                format!(
+                    "missing parameter '{}': record it under the input's params in \
+                     expected.toml, or pass {}=<value>",
+                    name, name
+                ),
+            )
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> Lines<'_> {
+        self.text.lines()
+    }
+
+    /// Parse each line in turn, reporting the first failure against its line number
+    pub fn parse_lines<T, E, F>(&self, mut parse: F) -> AocResult<Vec<T>>
+    where
+        E: Display,
+        F: FnMut(&str) -> Result<T, E>,
+    {
+        self.lines()
+            .enumerate()
+            .map(|(i, line)| parse(line).map_err(|e| AocError::parse(&self.path, i + 1, e)))
+            .collect()
+    }
+
+    /// Runs of non-blank lines, split on (any number of) blank lines
+    pub fn blocks(&self) -> Blocks<'_> {
+        Blocks {
+            text: &self.text,
+            lines: self.text.lines().enumerate().peekable(),
+        }
+    }
+}
+
+/// A run of consecutive non-blank lines
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// The (1-based) line number the block starts on
+    pub line: usize,
+    pub text: &'a str,
+}
+impl<'a> Block<'a> {
+    pub fn lines(&self) -> Lines<'a> {
+        self.text.lines()
+    }
+}
+
+pub struct Blocks<'a> {
+    text: &'a str,
+    lines: Peekable<Enumerate<Lines<'a>>>,
+}
+impl<'a> Blocks<'a> {
+    /// Byte offset of a line borrowed from `text`
+    fn offset(&self, line: &str) -> usize {
+        line.as_ptr() as usize - self.text.as_ptr() as usize
+    }
+}
+impl<'a> Iterator for Blocks<'a> {
+    type Item = Block<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, first) = self
+            .lines
+            .by_ref()
+            .find(|(_, line)| !line.trim().is_empty())?;
+        let mut last = first;
+        while let Some((_, line)) = self.lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            last = line;
+        }
+        let (start, end) = (self.offset(first), self.offset(last) + last.len());
+        Some(Block {
+            line: index + 1,
+            text: &self.text[start..end],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_split_blocks() {
+        let input = Input::from_text("test", "1000\r\n2000\r\n\r\n\r\n4000\n\n5000\n6000\n");
+        let blocks = input.blocks().collect::<Vec<_>>();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].lines().collect::<Vec<_>>(), vec!["1000", "2000"]);
+        assert_eq!(
+            blocks[1],
+            Block {
+                line: 5,
+                text: "4000"
+            }
+        );
+        assert_eq!(blocks[2].line, 7);
+        assert_eq!(blocks[2].text, "5000\n6000");
+        assert_eq!(Input::from_text("empty", "\n\n").blocks().count(), 0);
+    }
+
+    #[test]
+    fn should_parse_lines_with_location() {
+        let input = Input::from_text("snippet", "1\n2\nx\n");
+        assert_eq!(
+            input
+                .parse_lines(|l| l.parse::<i32>())
+                .map_err(|e| e.to_string()),
+            Err("snippet:3: invalid digit found in string".to_owned())
+        );
+        assert_eq!(input.lines().count(), 3);
+    }
+}
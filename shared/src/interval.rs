@@ -0,0 +1,286 @@
+//! Ranges of integers, and sets of them kept as sorted, disjoint runs.
+//!
+//! An [`Interval`] is half-open, running from `start` up to but not including `end`, which makes
+//! lengths and splits easy; puzzles usually give inclusive ranges, so [`Interval::closed`] builds
+//! one from its first and last values. An [`IntervalSet`] merges overlapping and touching
+//! intervals as they're inserted, so it stays as few runs as possible.
+use std::{
+    collections::BTreeMap,
+    ops::{Add, Sub},
+};
+
+/// The values from `start` up to but not including `end`. Intervals with `end <= start` are
+/// empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+impl<T> Interval<T>
+where
+    T: Copy + Ord,
+{
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is in this interval
+    pub fn covers(&self, other: &Interval<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether the two intervals share a value
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The values in both intervals, which may be empty
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+impl<T> Interval<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    /// The values from `first` to `last`, inclusive
+    pub fn closed(first: T, last: T) -> Interval<T> {
+        Interval::new(first, last + T::from(1))
+    }
+
+    /// The last value in the interval, or `None` if it's empty
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::from(1))
+    }
+
+    /// How many values are in the interval
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::from(0)
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+/// A set of values, stored as the fewest disjoint intervals that cover it
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    // Start to end of each run. Runs never overlap or touch.
+    runs: BTreeMap<T, T>,
+}
+impl<T> IntervalSet<T>
+where
+    T: Copy + Ord,
+{
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            runs: BTreeMap::new(),
+        }
+    }
+
+    /// Add every value of `interval`, merging it with any runs it overlaps or touches
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let Interval { mut start, mut end } = interval;
+        if let Some((&before, &before_end)) = self.runs.range(..=start).next_back() {
+            if before_end >= start {
+                start = before;
+                end = end.max(before_end);
+                self.runs.remove(&before);
+            }
+        }
+        while let Some((&next, &next_end)) = self.runs.range(start..=end).next() {
+            end = end.max(next_end);
+            self.runs.remove(&next);
+        }
+        self.runs.insert(start, end);
+    }
+
+    /// Take out every value of `interval`, splitting any run it falls inside
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let Interval { start, end } = interval;
+        if let Some((&before, &before_end)) = self.runs.range(..start).next_back() {
+            if before_end > start {
+                self.runs.insert(before, start);
+                if before_end > end {
+                    self.runs.insert(end, before_end);
+                }
+            }
+        }
+        while let Some((&next, &next_end)) = self.runs.range(start..end).next() {
+            self.runs.remove(&next);
+            if next_end > end {
+                self.runs.insert(end, next_end);
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.runs
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &end)| value < end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    /// The runs, in order
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.runs
+            .iter()
+            .map(|(&start, &end)| Interval::new(start, end))
+    }
+
+    /// The values in both sets
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        let mut theirs = other.iter().peekable();
+        for ours in self.iter() {
+            while let Some(their) = theirs.peek() {
+                result.insert(ours.intersection(their));
+                if their.end > ours.end {
+                    break;
+                }
+                theirs.next();
+            }
+        }
+        result
+    }
+
+    /// The values in this set but not `other`
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for interval in other.iter() {
+            result.remove(interval);
+        }
+        result
+    }
+
+    /// The values of `within` that aren't in this set
+    pub fn complement(&self, within: Interval<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        result.insert(within);
+        result.difference(self)
+    }
+
+    /// The intervals between consecutive runs
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .map(|(a, b)| Interval::new(a.end, b.start))
+    }
+}
+impl<T> IntervalSet<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    /// How many values are in the set
+    pub fn len(&self) -> T {
+        self.iter().fold(T::from(0), |total, run| total + run.len())
+    }
+}
+impl<T> Extend<Interval<T>> for IntervalSet<T>
+where
+    T: Copy + Ord,
+{
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+impl<T> FromIterator<Interval<T>> for IntervalSet<T>
+where
+    T: Copy + Ord,
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compare_intervals() {
+        let a = Interval::closed(2, 8);
+        assert_eq!((a.len(), a.last()), (7, Some(8)));
+        assert!(a.covers(&Interval::closed(3, 7)));
+        assert!(!a.covers(&Interval::closed(7, 9)));
+        assert!(a.overlaps(&Interval::closed(8, 9)));
+        assert!(!a.overlaps(&Interval::new(9, 12)));
+        assert_eq!(Interval::new(5u32, 5).last(), None);
+    }
+
+    #[test]
+    fn should_keep_sets_normalized() {
+        let mut set = [
+            Interval::closed(0i64, 10),
+            Interval::closed(2, 12),
+            Interval::closed(13, 14),
+            Interval::closed(20, 24),
+        ]
+        .into_iter()
+        .collect::<IntervalSet<i64>>();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Interval::new(0, 15), Interval::new(20, 25)]
+        );
+        assert_eq!(set.len(), 20);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), vec![Interval::new(15, 20)]);
+
+        set.remove(Interval::closed(4, 4));
+        set.remove(Interval::new(12, 22));
+        assert!(set.contains(3) && !set.contains(4) && !set.contains(21));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![
+                Interval::new(0, 4),
+                Interval::new(5, 12),
+                Interval::new(22, 25)
+            ]
+        );
+
+        let other = [Interval::new(3, 6), Interval::new(10, 30)]
+            .into_iter()
+            .collect::<IntervalSet<i64>>();
+        assert_eq!(
+            set.intersection(&other).iter().collect::<Vec<_>>(),
+            vec![
+                Interval::new(3, 4),
+                Interval::new(5, 6),
+                Interval::new(10, 12),
+                Interval::new(22, 25)
+            ]
+        );
+        assert_eq!(
+            set.difference(&other).iter().collect::<Vec<_>>(),
+            vec![Interval::new(0, 3), Interval::new(6, 10)]
+        );
+        assert_eq!(
+            set.complement(Interval::new(-2, 10))
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Interval::new(-2, 0), Interval::new(4, 5)]
+        );
+    }
+}
@@ -1,57 +1,264 @@
 use std::{
-    fs::File,
-    io::{self, BufRead, BufReader, Lines},
-    path::Path,
+    fmt::{self, Display},
+    fs,
+    time::Instant,
 };
 
+use expected::{Expected, Verification};
+use timing::{timing_table, Benchmark, Timings};
+
+pub use error::{AocError, AocResult};
+pub use input::Input;
+pub use params::{Param, ParamInfo, Params};
+
+pub mod cycle;
+pub mod error;
+pub mod expected;
+pub mod fetch;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod params;
+pub mod report;
+pub mod search;
+pub mod sparse;
+pub mod timing;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+/// The result of one part of a day's problem
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    // Free-form answers, including multi-line renderings
+    Text(String),
+    Unsolved,
+}
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(i64::try_from(value).expect("Answer should fit in an i64"))
+    }
+}
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(i64::try_from(value).expect("Answer should fit in an i64"))
+    }
+}
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "(unsolved)"),
+        }
+    }
 }
 
-pub fn segment_lines<F>(lines: Lines<BufReader<File>>, mut cut_criteria: F) -> Vec<Vec<String>>
-where
-    F: FnMut(String) -> bool,
-{
-    let mut groups: Vec<Vec<String>> = vec![];
-    let mut group: Vec<String> = vec![];
-    for line in lines {
-        let text = line.expect("Should be able to read a line");
-        if cut_criteria(text.clone()) {
-            groups.push(group.clone());
-            group = vec![];
-        } else {
-            group.push(text);
+/// The results of both parts of a day's problem
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+impl Answers {
+    pub fn new<A, B>(part1: A, part2: B) -> Answers
+    where
+        A: Into<Answer>,
+        B: Into<Answer>,
+    {
+        Answers {
+            part1: part1.into(),
+            part2: part2.into(),
+        }
+    }
+}
+impl Answers {
+    pub fn part(&self, part: usize) -> &Answer {
+        match part {
+            1 => &self.part1,
+            2 => &self.part2,
+            _ => panic!("There are only two parts, got part {}", part),
+        }
+    }
+
+    /// Render the answers to the given parts, one per line
+    pub fn describe(&self, parts: &[usize]) -> String {
+        let mut result = String::new();
+        for part in parts {
+            let label = if *part == 1 { "Part one" } else { "Part two" };
+            match self.part(*part) {
+                // Start multi-line renderings on their own line so they line up
+                Answer::Text(s) if s.contains('\n') => {
+                    result.push_str(&format!("{}:\n{}\n", label, s))
+                }
+                answer => result.push_str(&format!("{}: {}\n", label, answer)),
+            }
+        }
+        result
+    }
+}
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.describe(&[1, 2]))
+    }
+}
+
+/// A data file to run a solution against.
+///
+/// Files whose name ends in `-part1` or `-part2` (e.g. `test-part2.txt`) only apply to that part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputFile {
+    pub path: String,
+}
+impl InputFile {
+    pub fn new(path: &str) -> InputFile {
+        InputFile {
+            path: path.to_owned(),
         }
     }
-    if !group.is_empty() {
-        groups.push(group);
+
+    /// The name answers for this file are recorded under, e.g. `test` for `data/day-1/test.txt`
+    pub fn name(&self) -> String {
+        expected::input_name(&self.path)
+    }
+
+    pub fn parts(&self) -> Vec<usize> {
+        let name = self.name();
+        if name.ends_with("-part1") {
+            vec![1]
+        } else if name.ends_with("-part2") {
+            vec![2]
+        } else {
+            vec![1, 2]
+        }
     }
-    groups
 }
 
 pub trait Problem {
-    fn test_file(&self) -> String;
+    /// Every `test*.txt` file in the problem's data directory
+    fn test_files(&self) -> Vec<InputFile>;
     fn input_file(&self) -> String;
+    fn expected_file(&self) -> String;
+    /// Whether `path` is one of this day's own test or input files, rather than some other file
+    /// that happens to share a name with one
+    fn is_own_file(&self, path: &str) -> bool;
+    /// Read `path` (or stdin, for `-`) along with any `overrides`, and the params recorded for it
+    /// if it's one of the day's own files
+    fn read_input(&self, path: &str, overrides: &Params) -> AocResult<Input>;
+}
+/// The answers for one input, along with how long each phase took
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solved {
+    pub answers: Answers,
+    pub timings: Timings,
 }
+
 pub trait Solution {
-    fn solution(&self, path: &str);
+    /// The input, parsed into whatever both parts work from
+    type Parsed;
+
+    fn parse(&self, input: &Input) -> AocResult<Self::Parsed>;
+    /// The parameters `parse` reads from the input, if any
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![]
+    }
+    fn part_one(&self, parsed: &Self::Parsed) -> AocResult<Answer>;
+    fn part_two(&self, parsed: &Self::Parsed) -> AocResult<Answer>;
+
+    /// Read `path` (or stdin, for `-`) and solve the given parts of it
+    fn solve(&self, path: &str, parts: &[usize]) -> AocResult<Solved> {
+        self.solve_input(&Input::read(path)?, parts)
+    }
+
+    /// Parse the input and solve the given parts, timing each phase. Other parts are left unsolved.
+    fn solve_input(&self, input: &Input, parts: &[usize]) -> AocResult<Solved> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let mut timings = Timings {
+            parse: start.elapsed(),
+            ..Timings::default()
+        };
+        let mut answers = Answers::new(Answer::Unsolved, Answer::Unsolved);
+        if parts.contains(&1) {
+            let start = Instant::now();
+            answers.part1 = self.part_one(&parsed)?;
+            timings.part1 = Some(start.elapsed());
+        }
+        if parts.contains(&2) {
+            let start = Instant::now();
+            answers.part2 = self.part_two(&parsed)?;
+            timings.part2 = Some(start.elapsed());
+        }
+        Ok(Solved { answers, timings })
+    }
+
+    fn answers(&self, path: &str) -> AocResult<Answers> {
+        self.solve(path, &[1, 2]).map(|solved| solved.answers)
+    }
+
+    fn solution(&self, path: &str) {
+        match self.answers(path) {
+            Ok(answers) => print!("{}", answers),
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
 }
 pub trait AoCSolution {
-    fn test(&self);
-    fn run(&self);
+    /// Solve every test file, printing the answers and returning how long each took, or why it
+    /// couldn't be solved
+    fn test(&self) -> Vec<(String, AocResult<Timings>)>;
+    /// Solve the input, printing the answers and returning how long it took, or why it couldn't be
+    /// solved
+    fn run(&self) -> Vec<(String, AocResult<Timings>)>;
+    /// Solve the input `runs` times, summarizing how long each phase took
+    fn bench(&self, runs: usize) -> AocResult<Benchmark>;
+    /// Compare each part's answer for every input with the recorded expected answers.
+    ///
+    /// Inputs that fail to solve are reported as errors for each of their parts.
+    fn verify(&self) -> AocResult<Vec<Verification>>;
+    /// Solve both parts of an arbitrary file, or stdin for `-`. `params` take the place of any
+    /// recorded for an input of the same name.
+    fn solve_file(&self, path: &str, params: &Params) -> AocResult<Solved>;
+    /// The parameters the solution reads, with their defaults
+    fn declared_params(&self) -> Vec<ParamInfo>;
     fn test_and_run(&self) {
         println!("Testing:");
-        self.test();
+        let mut timings = self.test();
         println!("\nRunning:");
-        self.run();
+        timings.extend(self.run());
+        let timings = timings
+            .into_iter()
+            .filter_map(|(name, timings)| Some((name, timings.ok()?)))
+            .collect::<Vec<_>>();
+        println!("\n{}", timing_table(&timings));
     }
 }
 pub trait AoCProblem {
@@ -62,25 +269,164 @@ impl<T> Problem for T
 where
     T: AoCProblem,
 {
-    fn test_file(&self) -> String {
-        format!("data/{}/test.txt", &self.name())
+    fn test_files(&self) -> Vec<InputFile> {
+        let mut result = fs::read_dir(format!("data/{}", &self.name()))
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        let file_name = path
+                            .file_name()
+                            .map(|n| n.to_string_lossy().into_owned())
+                            .unwrap_or_default();
+                        file_name.starts_with("test") && file_name.ends_with(".txt")
+                    })
+                    .map(|path| InputFile::new(&path.to_string_lossy()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        result.sort_by_key(|input| input.name());
+        result
     }
 
     fn input_file(&self) -> String {
         format!("data/{}/input.txt", &self.name())
     }
+
+    fn expected_file(&self) -> String {
+        format!("data/{}/expected.toml", &self.name())
+    }
+
+    fn is_own_file(&self, path: &str) -> bool {
+        let Ok(path) = fs::canonicalize(path) else {
+            return false;
+        };
+        self.test_files()
+            .into_iter()
+            .map(|input| input.path)
+            .chain([self.input_file()])
+            .any(|own| fs::canonicalize(own).is_ok_and(|own| own == path))
+    }
+
+    fn read_input(&self, path: &str, overrides: &Params) -> AocResult<Input> {
+        let recorded = if self.is_own_file(path) {
+            Expected::load(self.expected_file())?.for_input(path).params
+        } else {
+            Params::new()
+        };
+        let params = recorded.merged(overrides);
+        Ok(Input::read(path)?.with_params(params))
+    }
 }
 
 impl<T> AoCSolution for T
 where
     T: Problem + Solution,
 {
-    fn test(&self) {
-        self.solution(&self.test_file())
+    fn test(&self) -> Vec<(String, AocResult<Timings>)> {
+        self.test_files()
+            .iter()
+            .map(|input| (input.name(), report(self, input)))
+            .collect()
+    }
+
+    fn run(&self) -> Vec<(String, AocResult<Timings>)> {
+        let input = InputFile::new(&self.input_file());
+        vec![(input.name(), report(self, &input))]
+    }
+
+    fn bench(&self, runs: usize) -> AocResult<Benchmark> {
+        let mut timings = vec![];
+        for _ in 0..runs {
+            let input = read_checked(self, &self.input_file(), &Params::new())?;
+            timings.push(self.solve_input(&input, &[1, 2])?.timings);
+        }
+        Benchmark::new(&timings)
+            .ok_or_else(|| AocError::invariant("Benchmarks need at least one run"))
+    }
+
+    fn solve_file(&self, path: &str, params: &Params) -> AocResult<Solved> {
+        self.solve_input(&read_checked(self, path, params)?, &[1, 2])
+    }
+
+    fn declared_params(&self) -> Vec<ParamInfo> {
+        self.params()
+    }
+
+    fn verify(&self) -> AocResult<Vec<Verification>> {
+        let expected = Expected::load(self.expected_file())?;
+        let mut inputs = self.test_files();
+        inputs.push(InputFile::new(&self.input_file()));
+        let mut result = vec![];
+        for input in inputs {
+            let expected_answers = expected.for_input(&input.path);
+            let solved = read_checked(self, &input.path, &Params::new())
+                .and_then(|text| self.solve_input(&text, &input.parts()));
+            for part in input.parts() {
+                result.push(match &solved {
+                    Ok(solved) => Verification {
+                        duration: solved.timings.part(part),
+                        ..Verification::check(
+                            &input.name(),
+                            part,
+                            solved.answers.part(part).to_owned(),
+                            expected_answers.part(part).to_owned(),
+                        )
+                    },
+                    Err(e) => Verification::error(&input.name(), part, e),
+                });
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Read an input with its params, rejecting any the solution doesn't declare
+fn read_checked<T>(solution: &T, path: &str, overrides: &Params) -> AocResult<Input>
+where
+    T: Problem + Solution,
+{
+    let input = solution.read_input(path, overrides)?;
+    let declared = solution.params();
+    if let Some(unknown) = input
+        .params()
+        .names()
+        .find(|name| !declared.iter().any(|param| param.name == *name))
+    {
+        let known = declared
+            .iter()
+            .map(|param| param.to_string())
+            .collect::<Vec<_>>();
+        let known = if known.is_empty() {
+            "none".to_owned()
+        } else {
+            known.join(", ")
+        };
+        return Err(AocError::parse_file(
+            path,
+            format!("unknown parameter '{}' (parameters: {})", unknown, known),
+        ));
     }
+    Ok(input)
+}
 
-    fn run(&self) {
-        self.solution(&self.input_file())
+fn report<T>(solution: &T, input: &InputFile) -> AocResult<Timings>
+where
+    T: Problem + Solution,
+{
+    println!("[{}]", input.name());
+    let solved = read_checked(solution, &input.path, &Params::new())
+        .and_then(|text| solution.solve_input(&text, &input.parts()));
+    match solved {
+        Ok(solved) => {
+            print!("{}", solved.answers.describe(&input.parts()));
+            Ok(solved.timings)
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            Err(e)
+        }
     }
 }
 
@@ -117,4 +463,27 @@ mod tests {
         let result = add(2, 2);
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn should_display_answers() {
+        let answers = Answers::new(24000, "#.\n.#");
+        assert_eq!(answers.part1, Answer::Number(24000));
+        assert_eq!(
+            format!("{}", answers),
+            "Part one: 24000\nPart two:\n#.\n.#\n"
+        );
+        assert_eq!(format!("{}", Answer::Unsolved), "(unsolved)");
+        assert_eq!(answers.describe(&[1]), "Part one: 24000\n");
+    }
+
+    #[test]
+    fn should_select_parts_by_file_name() {
+        assert_eq!(InputFile::new("data/day-9/test.txt").parts(), vec![1, 2]);
+        assert_eq!(InputFile::new("data/day-9/test2.txt").parts(), vec![1, 2]);
+        assert_eq!(InputFile::new("data/day-9/test-part2.txt").parts(), vec![2]);
+        assert_eq!(
+            InputFile::new("data/day-9/test-part2.txt").name(),
+            "test-part2"
+        );
+    }
 }
@@ -0,0 +1,190 @@
+//! Values a puzzle leaves up to each input, like which row to inspect.
+//!
+//! They're recorded alongside an input's expected answers in `data/day-{n}/expected.toml`:
+//!
+//! ```toml
+//! [test]
+//! part1 = 26
+//! params = { row = 10 }
+//! ```
+//!
+//! and can be given (or overridden) on the command line as `name=value`. A solution declares
+//! the parameters it reads as [`Param`]s, most with a default taken from the puzzle, so that
+//! only inputs exploring a different scenario need to say anything.
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use crate::{AocError, AocResult, Input};
+
+/// A parameter a solution reads, with the value to use when an input doesn't give one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param<T> {
+    pub name: &'static str,
+    pub default: Option<T>,
+}
+impl<T> Param<T> {
+    pub const fn new(name: &'static str, default: T) -> Param<T> {
+        Param {
+            name,
+            default: Some(default),
+        }
+    }
+
+    /// A parameter every input has to give
+    pub const fn required(name: &'static str) -> Param<T> {
+        Param {
+            name,
+            default: None,
+        }
+    }
+}
+impl<T> Param<T>
+where
+    T: Copy + Display + TryFrom<i64>,
+{
+    /// The input's value for this parameter, or the default if it doesn't give one
+    pub fn get(&self, input: &Input) -> AocResult<T> {
+        let value = match (input.params().get(self.name), self.default) {
+            (Some(value), _) => value,
+            (None, Some(default)) => return Ok(default),
+            (None, None) => input.param(self.name)?,
+        };
+        T::try_from(value).map_err(|_| {
+            AocError::parse_file(
+                input.path(),
+                format!("parameter '{}' is out of range: {}", self.name, value),
+            )
+        })
+    }
+
+    pub fn info(&self) -> ParamInfo {
+        ParamInfo {
+            name: self.name,
+            default: self.default.map(|default| default.to_string()),
+        }
+    }
+}
+
+/// A declared parameter, for listing and checking names against
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamInfo {
+    pub name: &'static str,
+    pub default: Option<String>,
+}
+impl Display for ParamInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.default {
+            Some(default) => write!(f, "{}={}", self.name, default),
+            None => write!(f, "{} (required)", self.name),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, i64>);
+impl Params {
+    pub fn new() -> Params {
+        Params(BTreeMap::new())
+    }
+
+    pub fn get(&self, name: &str) -> Option<i64> {
+        self.0.get(name).copied()
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.0.insert(name.to_owned(), value);
+    }
+
+    /// These values, with any given in `overrides` taking their place
+    pub fn merged(mut self, overrides: &Params) -> Params {
+        self.0.extend(
+            overrides
+                .0
+                .iter()
+                .map(|(name, value)| (name.clone(), *value)),
+        );
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Every parameter that's set, in order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(|name| name.as_str())
+    }
+
+    /// Parse command line assignments like `row=10`
+    pub fn parse_assignments<S>(assignments: &[S]) -> Result<Params, String>
+    where
+        S: AsRef<str>,
+    {
+        assignments
+            .iter()
+            .map(|assignment| {
+                let assignment = assignment.as_ref();
+                let (name, value) = assignment.split_once('=').ok_or(format!(
+                    "expected a parameter like name=value: {}",
+                    assignment
+                ))?;
+                let value = value
+                    .trim()
+                    .parse::<i64>()
+                    .map_err(|e| format!("invalid value for parameter '{}': {}", name, e))?;
+                Ok((name.trim().to_owned(), value))
+            })
+            .collect()
+    }
+}
+impl<S> FromIterator<(S, i64)> for Params
+where
+    S: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (S, i64)>>(values: I) -> Self {
+        Params(
+            values
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_override_params() {
+        let recorded = Params::from_iter([("row", 10), ("max_bound", 20)]);
+        let overrides =
+            Params::parse_assignments(&["row=2000000"]).expect("Should parse assignments");
+        let params = recorded.merged(&overrides);
+        assert_eq!(params.get("row"), Some(2000000));
+        assert_eq!(params.get("max_bound"), Some(20));
+        assert_eq!(params.get("other"), None);
+        assert!(Params::parse_assignments(&["row"]).is_err());
+        assert!(Params::parse_assignments(&["row=x"]).is_err());
+    }
+
+    #[test]
+    fn should_read_typed_params() {
+        const ROUNDS: Param<usize> = Param::new("rounds", 20);
+        const ROW: Param<i64> = Param::required("row");
+        let input = Input::from_text("example", "");
+        assert_eq!(ROUNDS.get(&input).ok(), Some(20));
+        assert!(ROW.get(&input).is_err());
+        assert_eq!(ROW.info().to_string(), "row (required)");
+        assert_eq!(ROUNDS.info().to_string(), "rounds=20");
+
+        let input = input.with_params(Params::from_iter([("rounds", 3), ("row", -2)]));
+        assert_eq!(ROUNDS.get(&input).ok(), Some(3));
+        assert_eq!(ROW.get(&input).ok(), Some(-2));
+        let input =
+            Input::from_text("example", "").with_params(Params::from_iter([("rounds", -1)]));
+        assert!(ROUNDS.get(&input).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+//! Machine-readable verification results, one record per day, input, and part.
+//!
+//! Each record carries the answer, the expected answer (if known), the verdict, and how long
+//! the part took to solve. Reports are written as JSON (an array of records) or CSV (a header
+//! row, then a row per record), picked by the output file's extension.
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::{
+    expected::{Verdict, Verification},
+    Answer, AocError, AocResult,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+impl Format {
+    pub fn from_path<P>(path: P) -> AocResult<Format>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Ok(Format::Json),
+            Some("csv") => Ok(Format::Csv),
+            _ => Err(AocError::invariant(format!(
+                "Report {} should end in .json or .csv",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// A single day's verification, as it appears in a report
+pub struct Record {
+    pub day: usize,
+    pub verification: Verification,
+}
+impl Record {
+    fn status(&self) -> &'static str {
+        match self.verification.verdict {
+            Verdict::Pass => "pass",
+            Verdict::Fail(_) => "fail",
+            Verdict::Missing => "missing",
+            Verdict::Error(_) => "error",
+        }
+    }
+
+    fn expected(&self) -> Option<&Answer> {
+        match &self.verification.verdict {
+            Verdict::Pass => Some(&self.verification.answer),
+            Verdict::Fail(expected) => Some(expected),
+            _ => None,
+        }
+    }
+
+    fn error(&self) -> Option<&str> {
+        match &self.verification.verdict {
+            Verdict::Error(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn duration_ms(&self) -> Option<f64> {
+        self.verification
+            .duration
+            .map(|d| d.as_nanos() as f64 / 1_000_000.0)
+    }
+}
+
+fn answer_to_json(answer: Option<&Answer>) -> Value {
+    match answer {
+        Some(Answer::Number(n)) => json!(n),
+        Some(Answer::Text(s)) => json!(s),
+        Some(Answer::Unsolved) | None => Value::Null,
+    }
+}
+
+fn answer_to_csv(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Number(n)) => n.to_string(),
+        Some(Answer::Text(s)) => s.to_owned(),
+        Some(Answer::Unsolved) | None => String::new(),
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote, or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let records = records
+        .iter()
+        .map(|record| {
+            json!({
+                "day": record.day,
+                "input": record.verification.input,
+                "part": record.verification.part,
+                "answer": answer_to_json(Some(&record.verification.answer)),
+                "expected": answer_to_json(record.expected()),
+                "status": record.status(),
+                "duration_ms": record.duration_ms(),
+                "error": record.error(),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&records).expect("Should serialize plain JSON values")
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut lines = vec!["day,input,part,answer,expected,status,duration_ms,error".to_owned()];
+    for record in records {
+        let fields = [
+            record.day.to_string(),
+            record.verification.input.to_owned(),
+            record.verification.part.to_string(),
+            answer_to_csv(Some(&record.verification.answer)),
+            answer_to_csv(record.expected()),
+            record.status().to_owned(),
+            record
+                .duration_ms()
+                .map(|ms| format!("{:.3}", ms))
+                .unwrap_or_default(),
+            record.error().unwrap_or_default().to_owned(),
+        ];
+        lines.push(
+            fields
+                .iter()
+                .map(|f| csv_field(f))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+    lines.join("\n") + "\n"
+}
+
+pub fn render(format: Format, records: &[Record]) -> String {
+    match format {
+        Format::Json => to_json(records),
+        Format::Csv => to_csv(records),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                verification: Verification {
+                    duration: Some(Duration::from_micros(1500)),
+                    ..Verification::check("test", 1, 24000.into(), Some(24000.into()))
+                },
+            },
+            Record {
+                day: 10,
+                verification: Verification::check("input", 2, "#.\n\"#\"".into(), None),
+            },
+        ]
+    }
+
+    #[test]
+    fn should_write_csv() {
+        assert_eq!(
+            to_csv(&records()),
+            "day,input,part,answer,expected,status,duration_ms,error\n\
+             1,test,1,24000,24000,pass,1.500,\n\
+             10,input,2,\"#.\n\"\"#\"\"\",,missing,,\n"
+        );
+    }
+
+    #[test]
+    fn should_write_json() {
+        let written: Value = serde_json::from_str(&to_json(&records())).expect("Should parse");
+        assert_eq!(
+            written[0],
+            json!({
+                "day": 1,
+                "input": "test",
+                "part": 1,
+                "answer": 24000,
+                "expected": 24000,
+                "status": "pass",
+                "duration_ms": 1.5,
+                "error": null,
+            })
+        );
+        assert_eq!(written[1]["answer"], json!("#.\n\"#\""));
+        assert_eq!(written[1]["expected"], Value::Null);
+        assert_eq!(
+            Format::from_path("results.csv").expect("Should be csv"),
+            Format::Csv
+        );
+        assert!(Format::from_path("results").is_err());
+    }
+}
@@ -0,0 +1,263 @@
+//! Shortest-path searches over graphs that are described by their edges rather than stored.
+//!
+//! A [`Graph`] only has to say which nodes can be reached from a given node and at what cost;
+//! nodes can be grid positions, valve names, or whole puzzle states. Breadth-first search
+//! treats every edge as a single step, while Dijkstra and A* use the edge costs.
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes reachable in one move from `node`, each with the cost of that move
+    fn neighbors(&self, node: &Self::Node) -> Vec<(Self::Node, usize)>;
+}
+
+/// A graph whose edges are given by a closure
+pub struct FromFn<N, F> {
+    neighbors: F,
+    node: PhantomData<fn(&N)>,
+}
+
+/// Wrap `neighbors` as a [`Graph`], for one-off searches that don't need their own type
+pub fn from_fn<N, F>(neighbors: F) -> FromFn<N, F>
+where
+    F: Fn(&N) -> Vec<(N, usize)>,
+{
+    FromFn {
+        neighbors,
+        node: PhantomData,
+    }
+}
+impl<N, F> Graph for FromFn<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> Vec<(N, usize)>,
+{
+    type Node = N;
+
+    fn neighbors(&self, node: &N) -> Vec<(N, usize)> {
+        (self.neighbors)(node)
+    }
+}
+
+/// The cheapest known cost to every node reached, and the node each was reached from
+#[derive(Clone, Debug)]
+pub struct Distances<N> {
+    distances: HashMap<N, usize>,
+    parents: HashMap<N, N>,
+}
+impl<N> Distances<N>
+where
+    N: Clone + Eq + Hash,
+{
+    fn new() -> Distances<N> {
+        Distances {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// The cost to reach `node`, if it could be reached at all
+    pub fn get(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// Every node reached, with its cost
+    pub fn iter(&self) -> impl Iterator<Item = (&N, usize)> {
+        self.distances.iter().map(|(node, d)| (node, *d))
+    }
+
+    /// A cheapest path from one of the starts to `node`, including both ends
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(&path[path.len() - 1]) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Fewest steps from `start` to every node it can reach, ignoring edge costs
+pub fn bfs<G>(graph: &G, start: G::Node) -> Distances<G::Node>
+where
+    G: Graph,
+{
+    multi_source_bfs(graph, [start])
+}
+
+/// Fewest steps from the nearest of `starts` to every node they can reach, ignoring edge costs
+pub fn multi_source_bfs<G, I>(graph: &G, starts: I) -> Distances<G::Node>
+where
+    G: Graph,
+    I: IntoIterator<Item = G::Node>,
+{
+    let mut result = Distances::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let steps = result.distances[&node] + 1;
+        for (next, _) in graph.neighbors(&node) {
+            if let Entry::Vacant(entry) = result.distances.entry(next.clone()) {
+                entry.insert(steps);
+                result.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    result
+}
+
+/// Cheapest cost from the nearest of `starts` to every node they can reach
+pub fn dijkstra<G, I>(graph: &G, starts: I) -> Distances<G::Node>
+where
+    G: Graph,
+    I: IntoIterator<Item = G::Node>,
+{
+    let mut result = Distances::new();
+    let mut frontier = Frontier::new();
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            frontier.push(0, start);
+        }
+    }
+    while let Some((cost, node)) = frontier.pop() {
+        if cost > result.distances[&node] {
+            // A cheaper route to this node was already expanded
+            continue;
+        }
+        for (next, step) in graph.neighbors(&node) {
+            let next_cost = cost + step;
+            if result.get(&next).is_some_and(|known| known <= next_cost) {
+                continue;
+            }
+            result.distances.insert(next.clone(), next_cost);
+            result.parents.insert(next.clone(), node.clone());
+            frontier.push(next_cost, next);
+        }
+    }
+    result
+}
+
+/// The cheapest path from `start` to any node satisfying `is_goal`, with its cost.
+/// `heuristic` must never overestimate the remaining cost, or the path may not be the cheapest.
+pub fn astar<G, P, H>(
+    graph: &G,
+    start: G::Node,
+    is_goal: P,
+    heuristic: H,
+) -> Option<(usize, Vec<G::Node>)>
+where
+    G: Graph,
+    P: Fn(&G::Node) -> bool,
+    H: Fn(&G::Node) -> usize,
+{
+    let mut known = Distances::new();
+    let mut frontier = Frontier::new();
+    known.distances.insert(start.clone(), 0);
+    // Each entry carries the cost it was queued with, so stale ones can be skipped
+    frontier.push(heuristic(&start), (0, start));
+    while let Some((_, (cost, node))) = frontier.pop() {
+        if cost > known.distances[&node] {
+            // A cheaper route to this node was already queued
+            continue;
+        }
+        if is_goal(&node) {
+            let path = known.path_to(&node).expect("Should have reached the goal");
+            return Some((cost, path));
+        }
+        for (next, step) in graph.neighbors(&node) {
+            let next_cost = cost + step;
+            if known.get(&next).is_some_and(|k| k <= next_cost) {
+                continue;
+            }
+            known.distances.insert(next.clone(), next_cost);
+            known.parents.insert(next.clone(), node.clone());
+            frontier.push(next_cost + heuristic(&next), (next_cost, next));
+        }
+    }
+    None
+}
+
+/// A min-priority queue of nodes. Nodes don't need to be ordered themselves: ties are broken by
+/// insertion order instead.
+struct Frontier<N> {
+    heap: BinaryHeap<Reverse<(usize, usize)>>,
+    nodes: Vec<Option<N>>,
+}
+impl<N> Frontier<N> {
+    fn new() -> Frontier<N> {
+        Frontier {
+            heap: BinaryHeap::new(),
+            nodes: vec![],
+        }
+    }
+
+    fn push(&mut self, priority: usize, node: N) {
+        self.heap.push(Reverse((priority, self.nodes.len())));
+        self.nodes.push(Some(node));
+    }
+
+    fn pop(&mut self) -> Option<(usize, N)> {
+        let Reverse((priority, index)) = self.heap.pop()?;
+        let node = self.nodes[index].take().expect("Should pop each node once");
+        Some((priority, node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of nodes 0..=10 where each node steps to the next for 1, or jumps 3 ahead for 5
+    fn line() -> FromFn<u32, impl Fn(&u32) -> Vec<(u32, usize)>> {
+        from_fn(|n: &u32| {
+            [(n + 1, 1), (n + 3, 5)]
+                .into_iter()
+                .filter(|(next, _)| *next <= 10)
+                .collect()
+        })
+    }
+
+    #[test]
+    fn should_count_steps() {
+        let graph = line();
+        let distances = bfs(&graph, 0);
+        assert_eq!(distances.get(&10), Some(4));
+        assert_eq!(distances.path_to(&10).map(|p| p.len()), Some(5));
+        assert_eq!(distances.get(&11), None);
+        assert_eq!(multi_source_bfs(&graph, [0, 8]).get(&10), Some(2));
+    }
+
+    #[test]
+    fn should_find_cheapest_paths() {
+        let graph = line();
+        let distances = dijkstra(&graph, [0]);
+        assert_eq!(distances.get(&10), Some(10));
+        assert_eq!(distances.path_to(&3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(
+            astar(&graph, 0, |n| *n == 7, |n| 7u32.saturating_sub(*n) as usize),
+            Some((7, vec![0, 1, 2, 3, 4, 5, 6, 7]))
+        );
+        assert_eq!(astar(&graph, 0, |n| *n == 11, |_| 0), None);
+
+        // Nodes queued again at a lower cost are only expanded once
+        let expanded = std::cell::Cell::new(0);
+        let counting = from_fn(|n: &u32| {
+            expanded.set(expanded.get() + 1);
+            graph.neighbors(n)
+        });
+        assert_eq!(astar(&counting, 0, |n| *n == 11, |_| 0), None);
+        assert_eq!(expanded.get(), 11);
+    }
+}
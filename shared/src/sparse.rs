@@ -0,0 +1,220 @@
+//! An unbounded grid that only stores the cells that have been set.
+//!
+//! Points have signed coordinates, with `y` growing downward when rendered. The grid keeps
+//! track of the smallest box holding every stored cell, so the occupied window can be drawn
+//! without knowing its extent up front.
+use std::collections::{hash_map, HashMap};
+
+use crate::geom::Point2;
+
+pub type Point = Point2<i64>;
+
+/// The inclusive box from `min` to `max`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+impl Bounds {
+    /// The box holding just `point`
+    pub fn at(point: Point) -> Bounds {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The smallest box holding both this one and `point`
+    pub fn including(self, point: Point) -> Bounds {
+        Bounds {
+            min: self.min.component_min(&point),
+            max: self.max.component_max(&point),
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Whether `point` lies on the edge of the box
+    fn on_edge(&self, point: Point) -> bool {
+        point.x == self.min.x
+            || point.x == self.max.x
+            || point.y == self.min.y
+            || point.y == self.max.y
+    }
+
+    pub fn width(&self) -> u64 {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Set the cell at `point`, returning what was there before
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(point),
+            None => Bounds::at(point),
+        });
+        self.cells.insert(point, value)
+    }
+
+    /// Clear the cell at `point`, shrinking the bounds if it was on their edge
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        if self.bounds.is_some_and(|b| b.on_edge(point)) {
+            self.bounds = self
+                .cells
+                .keys()
+                .fold(None, |bounds: Option<Bounds>, p| match bounds {
+                    Some(bounds) => Some(bounds.including(*p)),
+                    None => Some(Bounds::at(*p)),
+                });
+        }
+        Some(removed)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// The number of cells that are set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest box holding every cell that's set, or `None` if none are
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Every set cell, in no particular order
+    pub fn iter(&self) -> hash_map::Iter<'_, Point, T> {
+        self.cells.iter()
+    }
+
+    /// Draw `window` one row per line, choosing each point's character with `draw`
+    pub fn render_window<F>(&self, window: Bounds, draw: F) -> String
+    where
+        F: Fn(Point, Option<&T>) -> char,
+    {
+        (window.min.y..=window.max.y)
+            .map(|y| {
+                (window.min.x..=window.max.x)
+                    .map(|x| Point::new(x, y))
+                    .map(|point| draw(point, self.get(point)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Draw the occupied window; an empty grid draws as an empty string
+    pub fn render<F>(&self, draw: F) -> String
+    where
+        F: Fn(Point, Option<&T>) -> char,
+    {
+        match self.bounds {
+            Some(bounds) => self.render_window(bounds, draw),
+            None => String::new(),
+        }
+    }
+}
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, cells: I) {
+        for (point, value) in cells {
+            self.insert(point, value);
+        }
+    }
+}
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(cells);
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_track_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point::new(2, 3), 'a');
+        grid.insert(Point::new(-1, 5), 'b');
+        assert_eq!(grid.insert(Point::new(0, 4), 'c'), None);
+        assert_eq!(grid.insert(Point::new(0, 4), 'd'), Some('c'));
+        let bounds = grid.bounds().expect("Should have bounds");
+        assert_eq!(
+            (bounds.min, bounds.max),
+            (Point::new(-1, 3), Point::new(2, 5))
+        );
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+
+        // Removing an interior cell keeps the bounds, removing an edge cell shrinks them
+        grid.remove(Point::new(0, 4));
+        assert_eq!(grid.bounds(), Some(bounds));
+        grid.remove(Point::new(-1, 5));
+        assert_eq!(grid.bounds(), Some(Bounds::at(Point::new(2, 3))));
+        grid.remove(Point::new(2, 3));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn should_render_occupied_window() {
+        let grid = [(Point::new(0, 0), '#'), (Point::new(2, 1), 'o')]
+            .into_iter()
+            .collect::<SparseGrid<char>>();
+        assert_eq!(
+            grid.render(|_, cell| cell.copied().unwrap_or('.')),
+            "#..\n..o"
+        );
+        assert_eq!(
+            grid.render_window(
+                Bounds {
+                    min: Point::new(-1, 0),
+                    max: Point::new(0, 0)
+                },
+                |_, cell| cell.copied().unwrap_or('.')
+            ),
+            ".#"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! How long each phase of a solution takes, for single runs and repeated benchmarks.
+use std::time::Duration;
+
+/// Time spent parsing an input and solving each part. Parts that weren't run are `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+impl Timings {
+    pub fn part(&self, part: usize) -> Option<Duration> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+/// The spread of a set of samples
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+impl Stats {
+    /// None if there are no samples
+    pub fn new(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Statistics for each phase over repeated runs of the same input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Benchmark {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+impl Benchmark {
+    /// None if there are no runs
+    pub fn new(runs: &[Timings]) -> Option<Benchmark> {
+        Some(Benchmark {
+            runs: runs.len(),
+            parse: Stats::new(runs.iter().map(|t| t.parse).collect())?,
+            part1: Stats::new(runs.iter().filter_map(|t| t.part1).collect()),
+            part2: Stats::new(runs.iter().filter_map(|t| t.part2).collect()),
+            total: Stats::new(runs.iter().map(|t| t.total()).collect())?,
+        })
+    }
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    duration
+        .map(|d| format!("{:.2?}", d))
+        .unwrap_or_else(|| "-".to_owned())
+}
+
+fn render_table(header: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths = header.iter().map(|h| h.len()).collect::<Vec<_>>();
+    for row in rows.iter() {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.len());
+        }
+    }
+    let render_row = |cells: Vec<String>| {
+        cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| match i {
+                // Labels read left to right, durations line up on the right
+                0 => format!("{:<width$}", cell, width = widths[i]),
+                _ => format!("{:>width$}", cell, width = widths[i]),
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_owned()
+    };
+    let mut lines = vec![render_row(header.iter().map(|h| h.to_string()).collect())];
+    lines.push(
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("  "),
+    );
+    lines.extend(rows.into_iter().map(render_row));
+    lines.join("\n")
+}
+
+/// One row per labelled run, with a column for each phase
+pub fn timing_table(rows: &[(String, Timings)]) -> String {
+    render_table(
+        &["input", "parse", "part 1", "part 2", "total"],
+        rows.iter()
+            .map(|(label, t)| {
+                vec![
+                    label.to_owned(),
+                    format_duration(Some(t.parse)),
+                    format_duration(t.part1),
+                    format_duration(t.part2),
+                    format_duration(Some(t.total())),
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// One row per phase of each labelled benchmark, with its min/median/max
+pub fn benchmark_table(rows: &[(String, Benchmark)]) -> String {
+    let mut result = vec![];
+    for (label, benchmark) in rows {
+        let phases = [
+            ("parse", Some(benchmark.parse)),
+            ("part 1", benchmark.part1),
+            ("part 2", benchmark.part2),
+            ("total", Some(benchmark.total)),
+        ];
+        for (phase, stats) in phases {
+            result.push(vec![
+                format!("{} ({} runs)", label, benchmark.runs),
+                phase.to_owned(),
+                format_duration(stats.map(|s| s.min)),
+                format_duration(stats.map(|s| s.median)),
+                format_duration(stats.map(|s| s.max)),
+            ]);
+        }
+    }
+    render_table(&["input", "phase", "min", "median", "max"], result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn should_summarize_runs() {
+        let runs = [3, 1, 2].map(|n| Timings {
+            parse: ms(n),
+            part1: Some(ms(10 * n)),
+            part2: None,
+        });
+        let benchmark = Benchmark::new(&runs).expect("Should have runs");
+        assert_eq!(benchmark.runs, 3);
+        assert_eq!(
+            benchmark.parse,
+            Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(3)
+            }
+        );
+        assert_eq!(benchmark.part1.map(|s| s.median), Some(ms(20)));
+        assert_eq!(benchmark.part2, None);
+        assert_eq!(benchmark.total.max, ms(33));
+        assert_eq!(Benchmark::new(&[]), None);
+    }
+
+    #[test]
+    fn should_render_timing_table() {
+        let timings = Timings {
+            parse: ms(1),
+            part1: Some(ms(2)),
+            part2: None,
+        };
+        assert_eq!(
+            timing_table(&[("day-1 test".to_owned(), timings)]),
+            "input        parse  part 1  part 2   total\n\
+             ----------  ------  ------  ------  ------\n\
+             day-1 test  1.00ms  2.00ms       -  3.00ms"
+        );
+    }
+}